@@ -0,0 +1,82 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+// a problem in the puzzle input, pointing at the line and column where it was found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    // both line and column start at 1
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new<T: Into<String>, M: Into<String>>(line: usize, column: usize, text: T, message: M) -> ParseError {
+        ParseError { file: None, line, column, text: text.into(), message: message.into() }
+    }
+
+    // error about a token that is a slice of the given line, the column is worked out from the slice
+    pub fn at<M: Into<String>>(line_no: usize, line: &str, token: &str, message: M) -> ParseError {
+        ParseError::new(line_no, column_of(line, token), token, message)
+    }
+
+    // the parsers only see the text, the file name is attached by whoever read it
+    pub fn with_file<F: Display>(mut self, file: F) -> ParseError {
+        self.file = Some(file.to_string());
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}: {}", file, self.line, self.column, self.message)?,
+            None => write!(f, "line {}, column {}: {}", self.line, self.column, self.message)?,
+        }
+        if !self.text.is_empty() {
+            write!(f, ": '{}'", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+// 1-based column of token inside line, token has to be a slice of line (as returned by split and trim)
+pub fn column_of(line: &str, token: &str) -> usize {
+    let line_start = line.as_ptr() as usize;
+    let token_start = token.as_ptr() as usize;
+    if token_start < line_start || token_start > line_start + line.len() {
+        return 1;
+    }
+    line[..token_start - line_start].chars().count() + 1
+}
+
+// error type for the binaries' main, rust prints the Debug form of whatever main returns
+// so this one prints the readable message instead of the struct dump
+pub struct Report(Box<dyn Error>);
+
+impl<E: Into<Box<dyn Error>>> From<E> for Report {
+    fn from(e: E) -> Report {
+        Report(e.into())
+    }
+}
+
+impl std::fmt::Debug for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// the message of a panic caught with catch_unwind, panics carry a &str or a String
+pub fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        format!("panicked: {}", s)
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        format!("panicked: {}", s)
+    } else {
+        "panicked".to_string()
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt::{Display, Formatter};
+
+use crate::error::ParseError;
+use crate::parse::parse_digit_grid;
+
+// 2d map stored row by row in a single vector
+// x is the horizontal axis (column), y is the vertical one (row), (0, 0) is the top left corner
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+const NEIGHBOURS_4: [(i32, i32); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const NEIGHBOURS_8: [(i32, i32); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    // returns None if the rows are not all equally long
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |r| r.len());
+        if rows.iter().any(|r| r.len() != width) {
+            return None;
+        }
+
+        Some(Grid { width, height, cells: rows.into_iter().flatten().collect() })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    fn index(&self, x: i32, y: i32) -> Option<usize> {
+        if self.contains(x, y) {
+            Some(y as usize * self.width + x as usize)
+        } else {
+            None
+        }
+    }
+
+    // can ask outside of the grid, will get None
+    pub fn get(&self, x: i32, y: i32) -> Option<&T> {
+        self.index(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: i32, y: i32) -> Option<&mut T> {
+        self.index(x, y).map(move |i| &mut self.cells[i])
+    }
+
+    // all coordinates, row by row
+    pub fn coords(&self) -> impl Iterator<Item = (i32, i32)> {
+        let width = self.width as i32;
+        (0..self.height as i32).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    // up, left, right and down neighbours that are inside the grid
+    pub fn neighbours_4(&self, x: i32, y: i32) -> impl Iterator<Item = (i32, i32)> + '_ {
+        self.neighbours(x, y, &NEIGHBOURS_4)
+    }
+
+    // neighbours including the diagonal ones that are inside the grid
+    pub fn neighbours_8(&self, x: i32, y: i32) -> impl Iterator<Item = (i32, i32)> + '_ {
+        self.neighbours(x, y, &NEIGHBOURS_8)
+    }
+
+    fn neighbours(&self, x: i32, y: i32, offsets: &'static [(i32, i32)]) -> impl Iterator<Item = (i32, i32)> + '_ {
+        offsets.iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(move |(x, y)| self.contains(*x, *y))
+    }
+
+    // new grid of the same size with f applied to every cell
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on 0, an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1)).take(self.height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+}
+
+impl<T: From<u8>> Grid<T> {
+    // parses lines of single digits ("2199943210"), all lines have to be equally long
+    pub fn parse_digits<S: AsRef<str>>(lines: &[S]) -> Result<Grid<T>, ParseError> {
+        let rows = parse_digit_grid(lines)?;
+        Ok(Grid::from_rows(rows).expect("parse_digit_grid checks the row lengths"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for c in row {
+                write!(f, "{}", c)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+// the file every day reads by default
+pub const DEFAULT_INPUT: &str = "input";
+
+// environment variable used when no input is given on the command line
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+// where the puzzle input comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    // "-" means stdin, anything else is a path
+    pub fn parse(arg: &str) -> InputSource {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    // positional argument first, then AOC_INPUT, then the "input" file in the current directory
+    pub fn from_args<I: Iterator<Item = String>>(mut args: I) -> Result<InputSource, String> {
+        let arg = args.next();
+        if let Some(extra) = args.next() {
+            return Err(format!("unexpected argument '{}', expected a single input path", extra));
+        }
+
+        if let Some(a) = arg {
+            return Ok(InputSource::parse(&a));
+        }
+
+        match std::env::var(INPUT_ENV) {
+            Ok(v) if !v.is_empty() => Ok(InputSource::parse(&v)),
+            _ => Ok(InputSource::File(PathBuf::from(DEFAULT_INPUT))),
+        }
+    }
+
+    // same as from_args, for the binaries' own command line
+    pub fn from_env_args() -> Result<InputSource, String> {
+        InputSource::from_args(std::env::args().skip(1))
+    }
+
+    // for the streaming solvers, nothing is read until the lines are asked for
+    pub fn reader(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            InputSource::Stdin => Ok(Box::new(BufReader::new(io::stdin()))),
+            InputSource::File(path) => Ok(Box::new(BufReader::new(File::open(path)?))),
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut s = String::new();
+                io::stdin().read_to_string(&mut s)?;
+                Ok(s)
+            }
+            InputSource::File(path) => load_input(path),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+// reads the whole input file into a string
+pub fn load_input<P: AsRef<Path>>(path: P) -> io::Result<String> {
+    std::fs::read_to_string(path)
+}
+
+// iterates over the lines of the input file without loading it all at once
+pub fn input_lines<P: AsRef<Path>>(path: P) -> io::Result<impl Iterator<Item = io::Result<String>>> {
+    let f = File::open(path)?;
+    Ok(BufReader::new(f).lines())
+}
+
+// calls f with every line and its number (from 1), the line buffer is reused so memory doesn't grow with the input
+// line endings are stripped like str::lines does
+pub fn for_each_line<R, F, E>(mut reader: R, mut f: F) -> Result<(), E>
+where
+    R: BufRead,
+    F: FnMut(usize, &str) -> Result<(), E>,
+    E: From<io::Error>,
+{
+    let mut line = String::new();
+    let mut line_no = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+        line_no += 1;
+        let l = line.strip_suffix('\n').map_or(line.as_str(), |l| l.strip_suffix('\r').unwrap_or(l));
+        f(line_no, l)?;
+    }
+}
+
+// reads the input file into a vector of lines
+pub fn load_input_lines<P: AsRef<Path>>(path: P) -> io::Result<Vec<String>> {
+    input_lines(path)?.collect()
+}
@@ -0,0 +1,19 @@
+// shared helpers for all the days: input loading and the parsing bits that kept getting copied around
+
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod log;
+pub mod options;
+pub mod parse;
+pub mod prop;
+pub mod rng;
+pub mod solution;
+
+pub use error::{panic_message, ParseError, Report};
+pub use grid::Grid;
+pub use input::{for_each_line, input_lines, load_input, load_input_lines, InputSource, DEFAULT_INPUT, INPUT_ENV};
+pub use options::Options;
+pub use parse::{parse_comma_separated, parse_digit_grid, parse_token};
+pub use rng::Rng;
+pub use solution::{run_parts, stream, stream_parts, DayRun, PartRun, Solution, Streaming};
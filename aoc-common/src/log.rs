@@ -0,0 +1,189 @@
+use std::fmt::{Arguments, Display, Formatter};
+use std::io::Write;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::RwLock;
+
+// environment variable with the log filter, e.g. AOC_LOG=debug,day08=trace
+pub const LOG_ENV: &str = "AOC_LOG";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    // -v is debug and -vv trace, without it only warnings and errors are shown
+    pub fn from_verbosity(verbosity: u8) -> Level {
+        match verbosity {
+            0 => Level::Warn,
+            1 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "error" => Ok(Level::Error),
+            "warn" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!("unknown log level '{}', expected error, warn, info, debug or trace", s.trim())),
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// the level for every target without its own entry, and the targets (a crate or a module path) that have one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    pub default: Level,
+    pub targets: Vec<(String, Level)>,
+}
+
+impl Filter {
+    pub fn new(default: Level) -> Filter {
+        Filter { default, targets: Vec::new() }
+    }
+
+    // comma separated `level` or `target=level` entries, later ones win
+    pub fn parse(&mut self, spec: &str) -> Result<(), String> {
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            match entry.split_once('=') {
+                Some((target, level)) => {
+                    let target = target.trim().replace('-', "_");
+                    let level = level.parse()?;
+                    self.targets.retain(|(t, _)| *t != target);
+                    self.targets.push((target, level));
+                }
+                None => self.default = entry.parse()?,
+            }
+        }
+        Ok(())
+    }
+
+    // the most specific target wins: day08::decode over day08 over the default
+    pub fn level_for(&self, target: &str) -> Level {
+        self.targets.iter()
+            .filter(|(t, _)| target == t || target.strip_prefix(t.as_str()).is_some_and(|rest| rest.starts_with("::")))
+            .max_by_key(|(t, _)| t.len())
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    fn max_level(&self) -> Level {
+        self.targets.iter().map(|(_, l)| *l).fold(self.default, Level::max)
+    }
+}
+
+static FILTER: RwLock<Option<Filter>> = RwLock::new(None);
+// the most verbose level of the filter, so disabled calls don't have to take the lock
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+pub fn set_filter(filter: Filter) {
+    MAX_LEVEL.store(filter.max_level() as u8, Ordering::Relaxed);
+    *FILTER.write().unwrap_or_else(|e| e.into_inner()) = Some(filter);
+}
+
+// -v / -vv of the runner, refined by AOC_LOG
+pub fn init(verbosity: u8) -> Result<(), String> {
+    let mut filter = Filter::new(Level::from_verbosity(verbosity));
+    if let Ok(spec) = std::env::var(LOG_ENV) {
+        filter.parse(&spec).map_err(|e| format!("{}: {}", LOG_ENV, e))?;
+    }
+    set_filter(filter);
+    Ok(())
+}
+
+// for the day binaries, which only have AOC_LOG
+pub fn init_from_env() -> Result<(), String> {
+    init(0)
+}
+
+pub fn enabled(level: Level, target: &str) -> bool {
+    if level as u8 > MAX_LEVEL.load(Ordering::Relaxed) {
+        return false;
+    }
+    let filter = FILTER.read().unwrap_or_else(|e| e.into_inner());
+    let max = filter.as_ref().map_or(Level::Warn, |f| f.level_for(target));
+    level <= max
+}
+
+// logs go to stderr, stdout only carries answers
+pub fn write(level: Level, target: &str, message: Arguments) {
+    let _ = writeln!(std::io::stderr().lock(), "[{} {}] {}", level, target, message);
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level, module_path!()) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_the_most_specific_target() {
+        let mut filter = Filter::new(Level::Warn);
+        filter.parse("info, day08=trace, day08::decode=error").unwrap();
+        assert_eq!(filter.default, Level::Info);
+        assert_eq!(filter.level_for("day08"), Level::Trace);
+        assert_eq!(filter.level_for("day08::parse"), Level::Trace);
+        assert_eq!(filter.level_for("day08::decode"), Level::Error);
+        assert_eq!(filter.level_for("day080"), Level::Info);
+        assert_eq!(filter.max_level(), Level::Trace);
+
+        assert!(filter.parse("day08=loud").is_err());
+        assert!(filter.parse("verbose").is_err());
+    }
+}
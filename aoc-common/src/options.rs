@@ -0,0 +1,59 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+// the tunable puzzle constants of one day. every option has a name so that it can be set
+// from the command line or a config file, the defaults are the values from the puzzle
+pub trait Options: Default {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String>;
+    // every option with its current value, in the order they are documented
+    fn values(&self) -> Vec<(&'static str, String)>;
+    // checks the options that only make sense together
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+// for the days that have nothing to tune
+impl Options for () {
+    fn set(&mut self, key: &str, _value: &str) -> Result<(), String> {
+        Err(format!("unknown option '{}', this day has no options", key))
+    }
+
+    fn values(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+}
+
+pub fn parse_option<T: FromStr>(key: &str, value: &str) -> Result<T, String> {
+    value.trim().parse().map_err(|_| format!("invalid value '{}' for {}", value.trim(), key))
+}
+
+// comma separated, e.g. "3,57,1197,25137"
+pub fn parse_option_list<T: FromStr, const N: usize>(key: &str, value: &str) -> Result<[T; N], String> {
+    let items = value.split(',').map(|v| parse_option(key, v)).collect::<Result<Vec<T>, String>>()?;
+    let found = items.len();
+    items.try_into().map_err(|_| format!("expected {} comma separated values for {}, found {}", N, key, found))
+}
+
+pub fn format_option_list<T: Display>(items: &[T]) -> String {
+    items.iter().map(|i| i.to_string()).collect::<Vec<_>>().join(",")
+}
+
+pub fn unknown_option(key: &str, options: &impl Options) -> String {
+    let names: Vec<&str> = options.values().iter().map(|(name, _)| *name).collect();
+    format!("unknown option '{}', expected one of {}", key, names.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_values_and_lists() {
+        assert_eq!(parse_option::<u32>("days", " 80"), Ok(80));
+        assert_eq!(parse_option::<u32>("days", "x"), Err("invalid value 'x' for days".to_string()));
+        assert_eq!(parse_option_list::<i64, 4>("scores", "1,2,3,4"), Ok([1, 2, 3, 4]));
+        assert_eq!(parse_option_list::<i64, 4>("scores", "1,2"), Err("expected 4 comma separated values for scores, found 2".to_string()));
+        assert_eq!(format_option_list(&[1, 2, 3]), "1,2,3");
+    }
+}
@@ -0,0 +1,50 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
+// parses a single token of a line, token has to be a slice of line so that the column can be reported
+pub fn parse_token<T>(line_no: usize, line: &str, token: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    token.parse::<T>().map_err(|e| ParseError::at(line_no, line, token, format!("invalid number ({})", e)))
+}
+
+// parses a line like "3,4,3,1,2" into numbers
+pub fn parse_comma_separated<T>(line_no: usize, line: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let mut v = Vec::new();
+    for s in line.trim().split(',') {
+        v.push(parse_token(line_no, line, s.trim())?);
+    }
+    Ok(v)
+}
+
+// parses lines of single digits ("2199943210") into rows of numbers, all rows have to be equally long
+pub fn parse_digit_grid<T, S>(lines: &[S]) -> Result<Vec<Vec<T>>, ParseError>
+where
+    T: From<u8>,
+    S: AsRef<str>,
+{
+    let mut rows: Vec<Vec<T>> = Vec::new();
+    for (i, l) in lines.iter().enumerate() {
+        let mut row = Vec::new();
+        for (col, c) in l.as_ref().chars().enumerate() {
+            let d = c.to_digit(10).ok_or_else(|| ParseError::new(i + 1, col + 1, c, "expected a digit"))?;
+            row.push(T::from(d as u8));
+        }
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                let msg = format!("expected {} digits, found {}", first.len(), row.len());
+                return Err(ParseError::new(i + 1, 1, l.as_ref(), msg));
+            }
+        }
+        rows.push(row);
+    }
+    Ok(rows)
+}
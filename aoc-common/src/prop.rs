@@ -0,0 +1,146 @@
+use std::fmt::{self, Debug, Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::error::panic_message;
+use crate::rng::Rng;
+
+// a tiny property checker: random cases from a seed, failing cases get shrunk to a minimal one
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    pub cases: u32,
+    pub seed: u64,
+    // upper bound on the shrinking steps, every step tries all the candidates of the current case
+    pub max_shrinks: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { cases: 100, seed: 1, max_shrinks: 1000 }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Failure<T> {
+    // seed of the failing case, Rng::new(seed) generates the original case again
+    pub seed: u64,
+    pub original: T,
+    pub minimal: T,
+    pub message: String,
+    pub shrinks: u32,
+}
+
+impl<T: Debug> Display for Failure<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} (case seed {}, shrunk {} times)", self.message, self.seed, self.shrinks)?;
+        writeln!(f, "minimal counterexample: {:?}", self.minimal)?;
+        write!(f, "original counterexample: {:?}", self.original)
+    }
+}
+
+// runs the property on config.cases generated values, returns the number of cases checked.
+// panics inside the property count as failures
+pub fn check<T, G, S, P>(config: &Config, generate: G, shrink: S, property: P) -> Result<u32, Failure<T>>
+where
+    T: Clone,
+    G: Fn(&mut Rng) -> T,
+    S: Fn(&T) -> Vec<T>,
+    P: Fn(&T) -> Result<(), String>,
+{
+    let holds = |value: &T| match panic::catch_unwind(AssertUnwindSafe(|| property(value))) {
+        Ok(r) => r,
+        Err(p) => Err(panic_message(&*p)),
+    };
+
+    for case in 0..config.cases {
+        let seed = config.seed.wrapping_add(case as u64);
+        let original = generate(&mut Rng::new(seed));
+        let message = match holds(&original) {
+            Ok(()) => continue,
+            Err(m) => m,
+        };
+
+        // greedy shrinking: keep taking the first smaller candidate that still fails
+        let mut minimal = original.clone();
+        let mut message = message;
+        let mut shrinks = 0;
+        'shrinking: while shrinks < config.max_shrinks {
+            for candidate in shrink(&minimal) {
+                if let Err(m) = holds(&candidate) {
+                    minimal = candidate;
+                    message = m;
+                    shrinks += 1;
+                    continue 'shrinking;
+                }
+            }
+            break;
+        }
+
+        return Err(Failure { seed, original, minimal, message, shrinks });
+    }
+
+    Ok(config.cases)
+}
+
+// smaller versions of a number, closest to zero first
+pub fn shrink_number(n: u64) -> Vec<u64> {
+    let mut candidates = Vec::new();
+    for c in [0, n / 2, n.saturating_sub(1)] {
+        if c < n && !candidates.contains(&c) {
+            candidates.push(c);
+        }
+    }
+    candidates
+}
+
+// drops halves, then single elements, then shrinks the elements one by one
+pub fn shrink_vec<T: Clone>(items: &[T], shrink_item: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let mut candidates = Vec::new();
+    let n = items.len();
+    if n > 1 {
+        candidates.push(items[..n / 2].to_vec());
+        candidates.push(items[n / 2..].to_vec());
+    }
+    for i in 0..n {
+        let mut smaller = items.to_vec();
+        smaller.remove(i);
+        candidates.push(smaller);
+    }
+    for (i, item) in items.iter().enumerate() {
+        for s in shrink_item(item) {
+            let mut smaller = items.to_vec();
+            smaller[i] = s;
+            candidates.push(smaller);
+        }
+    }
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn passing_property_checks_all_cases() {
+        let config = Config { cases: 20, ..Config::default() };
+        let r = check(&config, |rng| rng.below(100), |n| shrink_number(*n), |n| if *n < 100 { Ok(()) } else { Err("too big".to_string()) });
+        assert_eq!(r.unwrap(), 20);
+    }
+
+    #[test]
+    fn shrinks_to_minimal_counterexample() {
+        let config = Config { cases: 50, ..Config::default() };
+        let generate = |rng: &mut Rng| (0..rng.below(20)).map(|_| rng.below(100)).collect::<Vec<u64>>();
+        let shrink = |v: &Vec<u64>| shrink_vec(v, |n| shrink_number(*n));
+        let failure = check(&config, generate, shrink, |v| {
+            match v.iter().find(|n| **n >= 10) {
+                Some(n) => Err(format!("{} is too big", n)),
+                None => Ok(()),
+            }
+        }).unwrap_err();
+
+        assert_eq!(failure.minimal, vec![10]);
+        assert_eq!(failure.message, "10 is too big");
+        let regenerated = generate(&mut Rng::new(failure.seed));
+        assert_eq!(regenerated, failure.original);
+    }
+}
@@ -0,0 +1,188 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::io::{self, BufRead};
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use crate::error::{panic_message, ParseError};
+use crate::input::{for_each_line, InputSource};
+use crate::options::Options;
+
+// one day of the puzzle: parsing is done once, both parts then work on the parsed input
+pub trait Solution {
+    const DAY: u32;
+    const TITLE: &'static str;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+    // () for the days without tunable constants
+    type Options: Options;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>>;
+
+    // days with options override these, parse/part1/part2 then run them with the defaults
+    fn parse_with(input: &str, _options: &Self::Options) -> Result<Self::Input, ParseError> {
+        Self::parse(input)
+    }
+    fn part1_with(input: &Self::Input, _options: &Self::Options) -> Result<Self::Answer1, Box<dyn Error>> {
+        Self::part1(input)
+    }
+    fn part2_with(input: &Self::Input, _options: &Self::Options) -> Result<Self::Answer2, Box<dyn Error>> {
+        Self::part2(input)
+    }
+
+    // reads and parses the input, parse errors point into the file they came from
+    fn load(source: &InputSource) -> Result<Self::Input, Box<dyn Error>> {
+        let text = source.read().map_err(|e| format!("failed to read {}: {}", source, e))?;
+        Ok(Self::parse(&text).map_err(|e| e.with_file(source))?)
+    }
+}
+
+// days that can solve the puzzle line by line, keeping running totals instead of the whole input
+pub trait Streaming: Solution {
+    type State;
+    // parts whose finish doesn't need more than the state, and whose state doesn't grow with the input
+    const STREAMED_PARTS: &'static [u32] = &[1, 2];
+
+    fn start(options: &Self::Options) -> Self::State;
+    fn feed(state: &mut Self::State, line_no: usize, line: &str) -> Result<(), ParseError>;
+    fn finish1(state: &Self::State) -> Result<Self::Answer1, Box<dyn Error>>;
+    fn finish2(state: &Self::State) -> Result<Self::Answer2, Box<dyn Error>>;
+}
+
+// feeds every line of the reader to the day
+pub fn stream<S: Streaming, R: BufRead>(reader: R, options: &S::Options) -> Result<S::State, Box<dyn Error>> {
+    let mut state = S::start(options);
+    for_each_line(reader, |line_no, line| S::feed(&mut state, line_no, line).map_err(Box::<dyn Error>::from))?;
+    Ok(state)
+}
+
+// answer of one part, errors are kept as strings so that results can be passed between threads
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    pub part: u32,
+    pub answer: Result<String, String>,
+    // time spent in part1/part2 only, parsing is measured separately
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct DayRun {
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+impl DayRun {
+    // every requested part fails with the same error, e.g. when the input can't be read
+    pub fn failed(parts: &[u32], error: &str) -> DayRun {
+        let parts = parts.iter().map(|part| PartRun { part: *part, answer: Err(error.to_string()), elapsed: Duration::ZERO }).collect();
+        DayRun { parse_elapsed: Duration::ZERO, parts }
+    }
+}
+
+// parses the input once and solves the requested parts, timing each stage
+// if parsing fails every requested part gets the parse error
+pub fn run_parts<S: Solution>(input: &str, source: &InputSource, parts: &[u32], options: &S::Options) -> DayRun {
+    let start = Instant::now();
+    let parsed = S::parse_with(input, options).map_err(|e| e.with_file(source));
+    let parse_elapsed = start.elapsed();
+
+    let parsed = match parsed {
+        Ok(p) => p,
+        Err(e) => return DayRun { parse_elapsed, ..DayRun::failed(parts, &e.to_string()) },
+    };
+
+    let parts = parts.iter().map(|part| {
+        let start = Instant::now();
+        let answer = solve_part(|| match part {
+            1 => S::part1_with(&parsed, options).map(|a| a.to_string()),
+            2 => S::part2_with(&parsed, options).map(|a| a.to_string()),
+            _ => Err(format!("unknown part {}", part).into()),
+        });
+        PartRun { part: *part, answer, elapsed: start.elapsed() }
+    }).collect();
+
+    DayRun { parse_elapsed, parts }
+}
+
+// a panic only fails the part it happened in, the other part still gets its answer
+fn solve_part(solve: impl FnOnce() -> Result<String, Box<dyn Error>>) -> Result<String, String> {
+    match panic::catch_unwind(AssertUnwindSafe(solve)) {
+        Ok(answer) => answer.map_err(|e| e.to_string()),
+        Err(p) => Err(panic_message(&*p)),
+    }
+}
+
+// like run_parts, reading the input while parsing it. parse_elapsed covers the reading too
+pub fn stream_parts<S: Streaming, R: BufRead>(reader: R, source: &InputSource, parts: &[u32], options: &S::Options) -> DayRun {
+    let start = Instant::now();
+    let state = stream::<S, R>(reader, options);
+    let parse_elapsed = start.elapsed();
+
+    let state = match state {
+        Ok(s) => s,
+        Err(e) => {
+            let error = match e.downcast::<ParseError>() {
+                Ok(e) => e.with_file(source).to_string(),
+                Err(e) if e.is::<io::Error>() => format!("failed to read {}: {}", source, e),
+                Err(e) => e.to_string(),
+            };
+            return DayRun { parse_elapsed, ..DayRun::failed(parts, &error) };
+        }
+    };
+
+    let parts = parts.iter().map(|part| {
+        let start = Instant::now();
+        let answer = solve_part(|| match part {
+            1 => S::finish1(&state).map(|a| a.to_string()),
+            2 => S::finish2(&state).map(|a| a.to_string()),
+            _ => Err(format!("unknown part {}", part).into()),
+        });
+        PartRun { part: *part, answer, elapsed: start.elapsed() }
+    }).collect();
+
+    DayRun { parse_elapsed, parts }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct PanicsInPart1;
+
+    impl Solution for PanicsInPart1 {
+        const DAY: u32 = 0;
+        const TITLE: &'static str = "test";
+
+        type Input = u32;
+        type Answer1 = u32;
+        type Answer2 = u32;
+        type Options = ();
+
+        fn parse(input: &str) -> Result<u32, ParseError> {
+            crate::parse_token(1, input, input.trim())
+        }
+
+        fn part1(n: &u32) -> Result<u32, Box<dyn Error>> {
+            Ok(n * u32::MAX)
+        }
+
+        fn part2(n: &u32) -> Result<u32, Box<dyn Error>> {
+            Ok(n + 1)
+        }
+    }
+
+    #[test]
+    fn a_panic_only_fails_its_part() {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let run = run_parts::<PanicsInPart1>("2", &InputSource::Stdin, &[1, 2], &());
+        panic::set_hook(hook);
+
+        assert!(run.parts[0].answer.as_ref().is_err_and(|e| e.starts_with("panicked")));
+        assert_eq!(run.parts[1].answer, Ok("3".to_string()));
+    }
+}
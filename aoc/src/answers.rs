@@ -0,0 +1,114 @@
+use std::path::{Path, PathBuf};
+
+use aoc_common::{parse_token, ParseError};
+
+pub const DEFAULT_ANSWERS: &str = "answers.toml";
+
+// known correct answer for one part of one day on one input file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u32,
+    // file name inside the day's directory, e.g. "input" or "example"
+    pub input: String,
+    pub part: u32,
+    pub value: String,
+}
+
+impl Answer {
+    // the inputs are looked up next to the answers file: <answers dir>/dayNN/<input>
+    pub fn input_path(&self, answers_file: &Path) -> PathBuf {
+        let dir = answers_file.parent().unwrap_or_else(|| Path::new(""));
+        dir.join(format!("day{:02}", self.day)).join(&self.input)
+    }
+}
+
+// parses a small subset of toml:
+//
+//     # comment
+//     [day05.input]
+//     part1 = 5576
+//     part2 = "18144"
+//
+// sections name the day and the input file, keys are the parts
+pub fn parse_answers(text: &str) -> Result<Vec<Answer>, ParseError> {
+    let mut answers = Vec::new();
+    let mut section: Option<(u32, String)> = None;
+
+    for (i, raw_line) in text.lines().enumerate() {
+        let line_no = i + 1;
+        let line = raw_line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[') {
+            let header = header.strip_suffix(']')
+                .ok_or_else(|| ParseError::at(line_no, raw_line, line, "missing ']' after section name"))?;
+            section = Some(parse_section(line_no, raw_line, header.trim())?);
+            continue;
+        }
+
+        let (day, input) = section.clone()
+            .ok_or_else(|| ParseError::at(line_no, raw_line, line, "answer outside of a [dayNN.input] section"))?;
+        let (key, value) = line.split_once('=')
+            .ok_or_else(|| ParseError::at(line_no, raw_line, line, "expected 'partN = answer'"))?;
+        let key = key.trim();
+        let part_str = key.strip_prefix("part")
+            .ok_or_else(|| ParseError::at(line_no, raw_line, key, "expected part1 or part2"))?;
+        let part: u32 = parse_token(line_no, raw_line, part_str)?;
+        if part != 1 && part != 2 {
+            return Err(ParseError::at(line_no, raw_line, key, "expected part1 or part2"));
+        }
+        let value = value.trim();
+        let value = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')).unwrap_or(value);
+        if value.is_empty() {
+            return Err(ParseError::at(line_no, raw_line, key, "missing answer"));
+        }
+
+        answers.push(Answer { day, input, part, value: value.to_string() });
+    }
+
+    Ok(answers)
+}
+
+fn parse_section(line_no: usize, line: &str, header: &str) -> Result<(u32, String), ParseError> {
+    let (day, input) = header.split_once('.')
+        .ok_or_else(|| ParseError::at(line_no, line, header, "expected [dayNN.input]"))?;
+    let day_str = day.strip_prefix("day")
+        .ok_or_else(|| ParseError::at(line_no, line, day, "expected [dayNN.input]"))?;
+    let day: u32 = parse_token(line_no, line, day_str)?;
+    if input.is_empty() {
+        return Err(ParseError::at(line_no, line, header, "missing input name"));
+    }
+
+    Ok((day, input.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_sections_and_parts() {
+        let text = "# comment\n[day05.input]\npart1 = 5576\npart2 = \"18144\"\n\n[day12.example_2]\npart1 = 19\n";
+        let answers = parse_answers(text).unwrap();
+        assert_eq!(answers, vec![
+            Answer { day: 5, input: "input".to_string(), part: 1, value: "5576".to_string() },
+            Answer { day: 5, input: "input".to_string(), part: 2, value: "18144".to_string() },
+            Answer { day: 12, input: "example_2".to_string(), part: 1, value: "19".to_string() },
+        ]);
+        assert_eq!(answers[2].input_path(Path::new("repo/answers.toml")), PathBuf::from("repo/day12/example_2"));
+    }
+
+    #[test]
+    fn reports_bad_lines() {
+        let e = parse_answers("part1 = 3\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+
+        let e = parse_answers("[day01.input]\npart3 = 3\n").unwrap_err();
+        assert_eq!((e.line, e.text.as_str()), (2, "part3"));
+
+        let e = parse_answers("[dayxx.input]\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 5));
+    }
+}
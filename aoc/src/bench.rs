@@ -0,0 +1,210 @@
+use std::collections::BTreeMap;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use aoc_common::{load_input, parse_token, ParseError};
+
+use crate::cli::BenchArgs;
+use crate::days::find_day;
+
+pub const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+// min, median and max of one stage over all the runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn of(samples: &[Duration]) -> Option<Stats> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let median = match sorted.len() {
+            0 => return None,
+            n if n % 2 == 1 => sorted[n / 2],
+            n => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+        };
+        Some(Stats { min: sorted[0], median, max: sorted[sorted.len() - 1] })
+    }
+}
+
+// runs every stage of the day `runs` times, parsing is redone for every run
+pub fn bench(args: &BenchArgs) -> ExitCode {
+    let day = match find_day(args.day) {
+        Some(d) => d,
+        None => {
+            eprintln!("error: day {} is not solved", args.day);
+            return ExitCode::from(2);
+        }
+    };
+    let source = args.input.clone().unwrap_or_else(|| day.default_input());
+    let input = match source.read() {
+        Ok(i) => i,
+        Err(e) => {
+            eprintln!("error: failed to read {}: {}", source, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let baseline = match &args.baseline {
+        Some(path) => match load_input(path).map_err(|e| e.to_string()).and_then(|t| parse_baseline(&t).map_err(|e| e.with_file(path.display()).to_string())) {
+            Ok(b) => Some(b),
+            Err(e) => {
+                eprintln!("error: failed to load baseline {}: {}", path.display(), e);
+                return ExitCode::from(2);
+            }
+        },
+        None => None,
+    };
+
+    let mut samples: [Vec<Duration>; 3] = Default::default();
+    for _ in 0..args.runs {
+        let day_run = day.run(&input, &source, &[1, 2]);
+        samples[0].push(day_run.parse_elapsed);
+        for (i, part) in day_run.parts.iter().enumerate() {
+            if let Err(e) = &part.answer {
+                eprintln!("day {:02} part {}: error: {}", day.day, part.part, e);
+                return ExitCode::FAILURE;
+            }
+            samples[i + 1].push(part.elapsed);
+        }
+    }
+
+    println!("day {:02}: {} runs on {}", day.day, args.runs, source);
+    println!("{:<6} {:>10} {:>10} {:>10}{}", "stage", "min", "median", "max",
+        if baseline.is_some() { format!(" {:>10} {:>8}", "baseline", "change") } else { String::new() });
+    let mut medians = Vec::new();
+    for (stage, stage_samples) in STAGES.iter().zip(samples.iter()) {
+        let stats = match Stats::of(stage_samples) {
+            Some(s) => s,
+            None => continue,
+        };
+        let mut line = format!("{:<6} {:>10} {:>10} {:>10}", stage,
+            format_duration(stats.min), format_duration(stats.median), format_duration(stats.max));
+        if let Some(b) = &baseline {
+            match b.get(&(day.day, stage.to_string())) {
+                Some(old) => line += &format!(" {:>10} {:>8}", format_duration(*old), format_change(*old, stats.median)),
+                None => line += &format!(" {:>10} {:>8}", "-", "-"),
+            }
+        }
+        println!("{}", line);
+        medians.push((stage.to_string(), stats.median));
+    }
+
+    if let Some(path) = &args.save {
+        // keep the other days already stored in the file
+        let mut saved = match load_input(path) {
+            Ok(text) => match parse_baseline(&text) {
+                Ok(b) => b,
+                Err(e) => {
+                    eprintln!("error: not overwriting {}: {}", path.display(), e.with_file(path.display()));
+                    return ExitCode::from(2);
+                }
+            },
+            Err(_) => Baseline::new(),
+        };
+        for (stage, median) in medians {
+            saved.insert((day.day, stage), median);
+        }
+        if let Err(e) = std::fs::write(path, format_baseline(&saved)) {
+            eprintln!("error: failed to write {}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+        println!("saved medians to {}", path.display());
+    }
+
+    ExitCode::SUCCESS
+}
+
+// median time per (day, stage), stored one per line as `day07.part1 = 123456` in nanoseconds
+pub type Baseline = BTreeMap<(u32, String), Duration>;
+
+pub fn parse_baseline(text: &str) -> Result<Baseline, ParseError> {
+    let mut baseline = Baseline::new();
+    for (i, raw_line) in text.lines().enumerate() {
+        let line_no = i + 1;
+        let line = raw_line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (key, value) = line.split_once('=')
+            .ok_or_else(|| ParseError::at(line_no, raw_line, line, "expected 'dayNN.stage = nanoseconds'"))?;
+        let key = key.trim();
+        let (day, stage) = key.split_once('.')
+            .ok_or_else(|| ParseError::at(line_no, raw_line, key, "expected 'dayNN.stage = nanoseconds'"))?;
+        let day_str = day.strip_prefix("day")
+            .ok_or_else(|| ParseError::at(line_no, raw_line, day, "expected dayNN"))?;
+        let day: u32 = parse_token(line_no, raw_line, day_str)?;
+        if !STAGES.contains(&stage) {
+            return Err(ParseError::at(line_no, raw_line, stage, "expected parse, part1 or part2"));
+        }
+        let nanos: u64 = parse_token(line_no, raw_line, value.trim())?;
+
+        baseline.insert((day, stage.to_string()), Duration::from_nanos(nanos));
+    }
+
+    Ok(baseline)
+}
+
+pub fn format_baseline(baseline: &Baseline) -> String {
+    let mut text = String::from("# median wall time per stage in nanoseconds, written by aoc bench --save\n");
+    for ((day, stage), median) in baseline {
+        text += &format!("day{:02}.{} = {}\n", day, stage, median.as_nanos());
+    }
+    text
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+fn format_change(old: Duration, new: Duration) -> String {
+    if old.is_zero() {
+        return "-".to_string();
+    }
+    let change = (new.as_secs_f64() / old.as_secs_f64() - 1.0) * 100.0;
+    format!("{:+.1}%", change)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_of_samples() {
+        let ms = |v: &[u64]| v.iter().map(|m| Duration::from_millis(*m)).collect::<Vec<_>>();
+        let s = Stats::of(&ms(&[5, 1, 3])).unwrap();
+        assert_eq!((s.min, s.median, s.max), (Duration::from_millis(1), Duration::from_millis(3), Duration::from_millis(5)));
+        assert_eq!(Stats::of(&ms(&[4, 1, 2, 8])).unwrap().median, Duration::from_millis(3));
+        assert_eq!(Stats::of(&[]), None);
+    }
+
+    #[test]
+    fn baseline_round_trip() {
+        let mut baseline = Baseline::new();
+        baseline.insert((7, "part1".to_string()), Duration::from_nanos(1234));
+        baseline.insert((12, "parse".to_string()), Duration::from_nanos(99));
+        assert_eq!(parse_baseline(&format_baseline(&baseline)).unwrap(), baseline);
+
+        let e = parse_baseline("day07.part3 = 5\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 7));
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(Duration::from_nanos(512)), "512ns");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.50ms");
+        assert_eq!(format_change(Duration::from_millis(10), Duration::from_millis(12)), "+20.0%");
+    }
+}
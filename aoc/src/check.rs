@@ -0,0 +1,417 @@
+use std::collections::HashSet;
+use std::process::ExitCode;
+
+use aoc_common::{parse_token, ParseError};
+
+use crate::cli::CheckArgs;
+use crate::days::find_day;
+
+// one day's input format. detect only looks at the start of the file, validate goes through all of it
+struct Format {
+    day: u32,
+    shape: &'static str,
+    detect: fn(&[&str]) -> bool,
+    validate: fn(&[&str]) -> Vec<ParseError>,
+}
+
+// the more specific formats first, they win when two formats fit equally well:
+// 0/1 lines are numbers too, a 10x10 grid is a day09 map too and grid lines are numbers
+const FORMATS: [Format; 12] = [
+    Format { day: 3, shape: "lines of 0s and 1s", detect: looks_like_bits, validate: validate_bits },
+    Format { day: 11, shape: "a grid of digits", detect: looks_like_octopuses, validate: validate_digit_grid },
+    Format { day: 9, shape: "a grid of digits", detect: looks_like_digit_grid, validate: validate_digit_grid },
+    Format { day: 1, shape: "a number per line", detect: looks_like_depths, validate: validate_depths },
+    Format { day: 2, shape: "a command per line", detect: looks_like_commands, validate: validate_commands },
+    Format { day: 4, shape: "drawn numbers and bingo boards", detect: looks_like_bingo, validate: validate_bingo },
+    Format { day: 5, shape: "x,y -> x,y lines", detect: looks_like_vent_lines, validate: validate_vent_lines },
+    Format { day: 6, shape: "a line of comma separated numbers", detect: looks_like_number_list, validate: validate_fish },
+    Format { day: 7, shape: "a line of comma separated numbers", detect: looks_like_number_list, validate: validate_crabs },
+    Format { day: 8, shape: "patterns | outputs lines", detect: looks_like_patterns, validate: validate_patterns },
+    Format { day: 10, shape: "lines of brackets", detect: looks_like_brackets, validate: validate_brackets },
+    Format { day: 12, shape: "a-b passages", detect: looks_like_caves, validate: validate_caves },
+];
+
+// the days whose format fits, best first, with the problems found for each
+pub fn detect(lines: &[&str]) -> Vec<(u32, Vec<ParseError>)> {
+    let mut candidates: Vec<(u32, Vec<ParseError>)> = formats_of(lines).map(|f| (f.day, (f.validate)(lines))).collect();
+    // stable, so ties keep the order of FORMATS
+    candidates.sort_by_key(|(_, problems)| problems.len());
+    candidates
+}
+
+fn formats_of<'a>(lines: &'a [&'a str]) -> impl Iterator<Item = &'static Format> + 'a {
+    FORMATS.iter().filter(|f| (f.detect)(lines))
+}
+
+fn shape_of(day: u32) -> &'static str {
+    FORMATS.iter().find(|f| f.day == day).map_or("", |f| f.shape)
+}
+
+pub fn validate(day: u32, lines: &[&str]) -> Option<Vec<ParseError>> {
+    FORMATS.iter().find(|f| f.day == day).map(|f| (f.validate)(lines))
+}
+
+pub fn check(args: &CheckArgs) -> ExitCode {
+    let text = match args.input.read() {
+        Ok(t) => t,
+        Err(e) => {
+            eprintln!("error: failed to read {}: {}", args.input, e);
+            return ExitCode::from(2);
+        }
+    };
+    let lines: Vec<&str> = text.lines().collect();
+    if lines.iter().all(|l| l.trim().is_empty()) {
+        eprintln!("{}: the file is empty", args.input);
+        return ExitCode::FAILURE;
+    }
+
+    let (day, problems) = match args.day {
+        Some(day) => match validate(day, &lines) {
+            Some(problems) => (day, problems),
+            None => {
+                eprintln!("error: day {} has no input format to check", day);
+                return ExitCode::from(2);
+            }
+        },
+        None => {
+            let mut candidates = detect(&lines);
+            if candidates.is_empty() {
+                let mut shapes: Vec<(u32, &str)> = FORMATS.iter().map(|f| (f.day, f.shape)).collect();
+                shapes.sort_unstable();
+                let shapes: Vec<String> = shapes.iter().map(|(day, shape)| format!("day {:02}: {}", day, shape)).collect();
+                eprintln!("{}: doesn't look like any day's input, the first line is '{}'. the inputs are\n    {}", args.input, lines[0], shapes.join("\n    "));
+                return ExitCode::FAILURE;
+            }
+            let (day, problems) = candidates.remove(0);
+            // only the days with the same kind of input are worth a mention, 0/1 lines are numbers too but nobody mixes those up
+            let others: Vec<String> = candidates.iter()
+                .filter(|(d, p)| p.len() == problems.len() && shape_of(*d) == shape_of(day))
+                .map(|(d, _)| format!("{:02}", d))
+                .collect();
+            let title = find_day(day).map_or("", |d| d.title);
+            match others.is_empty() {
+                true => println!("{}: looks like day {:02} ({})", args.input, day, title),
+                false => println!("{}: looks like day {:02} ({}), day {} has {} too", args.input, day, title, others.join(" and "), shape_of(day)),
+            }
+            (day, problems)
+        }
+    };
+
+    if problems.is_empty() {
+        println!("{}: valid day {:02} input, {} line{}", args.input, day, lines.len(), if lines.len() == 1 { "" } else { "s" });
+        return ExitCode::SUCCESS;
+    }
+    for p in &problems {
+        eprintln!("{}", p.clone().with_file(&args.input));
+    }
+    eprintln!("{} problem{} found", problems.len(), if problems.len() == 1 { "" } else { "s" });
+    ExitCode::FAILURE
+}
+
+fn numbered<'a>(lines: &'a [&'a str]) -> impl Iterator<Item = (usize, &'a str)> {
+    lines.iter().enumerate().map(|(i, l)| (i + 1, *l))
+}
+
+fn first_line<'a>(lines: &[&'a str]) -> &'a str {
+    lines.first().map_or("", |l| l.trim())
+}
+
+fn is_digits(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_bracket(c: char) -> bool {
+    "([{<)]}>".contains(c)
+}
+
+// every line has to be exactly as wide as the first one
+fn check_width(problems: &mut Vec<ParseError>, lines: &[&str], what: &str) {
+    let width = lines.first().map_or(0, |l| l.chars().count());
+    for (line_no, l) in numbered(lines) {
+        let len = l.chars().count();
+        if len != width {
+            problems.push(ParseError::new(line_no, 1, l, format!("expected {} {}, found {}", width, what, len)));
+        }
+    }
+}
+
+fn looks_like_depths(lines: &[&str]) -> bool {
+    first_line(lines).parse::<i64>().is_ok()
+}
+
+fn validate_depths(lines: &[&str]) -> Vec<ParseError> {
+    numbered(lines).filter_map(|(line_no, l)| parse_token::<i64>(line_no, l, l.trim()).err()).collect()
+}
+
+fn looks_like_commands(lines: &[&str]) -> bool {
+    ["forward ", "down ", "up "].iter().any(|c| first_line(lines).starts_with(c))
+}
+
+fn validate_commands(lines: &[&str]) -> Vec<ParseError> {
+    numbered(lines).filter_map(|(line_no, l)| day02::parse_command(line_no, l).err()).collect()
+}
+
+fn looks_like_bits(lines: &[&str]) -> bool {
+    let first = first_line(lines);
+    first.len() > 1 && first.chars().all(|c| c == '0' || c == '1')
+}
+
+fn validate_bits(lines: &[&str]) -> Vec<ParseError> {
+    let mut problems = Vec::new();
+    for (line_no, l) in numbered(lines) {
+        for (col, c) in l.chars().enumerate() {
+            if c != '0' && c != '1' {
+                problems.push(ParseError::new(line_no, col + 1, c, "expected 0 or 1"));
+            }
+        }
+    }
+    check_width(&mut problems, lines, "bits");
+    if let Some((line_no, l)) = numbered(lines).next().filter(|(_, l)| l.chars().count() > day03::MAX_BITS) {
+        problems.push(ParseError::new(line_no, 1, l, format!("expected at most {} bits, found {}", day03::MAX_BITS, l.chars().count())));
+    }
+    problems.sort_by_key(|p| (p.line, p.column));
+    problems
+}
+
+fn looks_like_bingo(lines: &[&str]) -> bool {
+    first_line(lines).contains(',') && lines.get(1).is_some_and(|l| l.trim().is_empty())
+}
+
+// the drawn numbers, then 5x5 boards each after an empty line
+fn validate_bingo(lines: &[&str]) -> Vec<ParseError> {
+    const BOARD_SIZE: usize = 5;
+    let mut problems = Vec::new();
+    let first = lines.first().copied().unwrap_or_default();
+    for token in first.split(',') {
+        problems.extend(parse_token::<u32>(1, first, token.trim()).err());
+    }
+
+    let mut boards = 0;
+    let mut line_no = 2;
+    while line_no <= lines.len() {
+        let l = lines[line_no - 1];
+        if !l.trim().is_empty() {
+            problems.push(ParseError::new(line_no, 1, l, "expected an empty line before the board"));
+        }
+        // the board goes on until the next empty line, so one bad board doesn't throw off the rest
+        let start = line_no + 1;
+        let mut end = start;
+        while end <= lines.len() && !lines[end - 1].trim().is_empty() {
+            end += 1;
+        }
+        let rows = end - start;
+        if rows != BOARD_SIZE {
+            problems.push(ParseError::new(start.min(lines.len()), 1, "", format!("expected a board of {} lines, found {}", BOARD_SIZE, rows)));
+        }
+        for row_no in start..end {
+            let row = lines[row_no - 1];
+            let numbers: Vec<&str> = row.split_whitespace().collect();
+            for n in &numbers {
+                problems.extend(parse_token::<u32>(row_no, row, n).err());
+            }
+            if numbers.len() != BOARD_SIZE {
+                problems.push(ParseError::new(row_no, 1, row, format!("expected {} numbers on a bingo line, found {}", BOARD_SIZE, numbers.len())));
+            }
+        }
+        boards += 1;
+        line_no = end;
+    }
+    if boards == 0 {
+        problems.push(ParseError::new(lines.len() + 1, 1, "", "no bingo boards found"));
+    }
+    problems
+}
+
+fn looks_like_vent_lines(lines: &[&str]) -> bool {
+    first_line(lines).contains("->")
+}
+
+fn validate_vent_lines(lines: &[&str]) -> Vec<ParseError> {
+    numbered(lines).filter_map(|(line_no, l)| day05::parse_line(line_no, l, day05::MAP_SIDE).err()).collect()
+}
+
+// stray lines after the list are left for validate to report
+fn looks_like_number_list(lines: &[&str]) -> bool {
+    let first = first_line(lines);
+    first.contains(',') && !first.contains("->") && !looks_like_bingo(lines)
+}
+
+// a single line of comma separated numbers up to max
+fn validate_number_list(lines: &[&str], max: u64) -> Vec<ParseError> {
+    let mut problems = Vec::new();
+    let first = lines.first().copied().unwrap_or_default();
+    for token in first.split(',').map(str::trim) {
+        match parse_token::<u64>(1, first, token) {
+            Ok(n) if n > max => problems.push(ParseError::at(1, first, token, format!("expected a number between 0 and {}", max))),
+            Ok(_) => {}
+            Err(e) => problems.push(e),
+        }
+    }
+    for (line_no, l) in numbered(lines).skip(1).filter(|(_, l)| !l.trim().is_empty()) {
+        problems.push(ParseError::new(line_no, 1, l, "expected a single line"));
+    }
+    problems
+}
+
+fn validate_fish(lines: &[&str]) -> Vec<ParseError> {
+    validate_number_list(lines, day06::Day06Options::default().newborn_timer)
+}
+
+fn validate_crabs(lines: &[&str]) -> Vec<ParseError> {
+    validate_number_list(lines, u64::from(u32::MAX))
+}
+
+fn looks_like_patterns(lines: &[&str]) -> bool {
+    first_line(lines).contains('|')
+}
+
+// 10 unique patterns | 4 outputs of the segments a to g, with the easy digits 1, 4, 7 and 8 once each
+fn validate_patterns(lines: &[&str]) -> Vec<ParseError> {
+    let mut problems = Vec::new();
+    for (line_no, l) in numbered(lines) {
+        let Some((patterns, outputs)) = l.split_once('|').filter(|(_, o)| !o.contains('|')) else {
+            problems.push(ParseError::new(line_no, 1, l, "expected signal patterns and output separated by a single '|'"));
+            continue;
+        };
+        for (part, expected, what) in [(patterns, 10, "signal patterns"), (outputs, 4, "output digits")] {
+            let words: Vec<&str> = part.split_whitespace().collect();
+            if words.len() != expected {
+                problems.push(ParseError::at(line_no, l, part.trim(), format!("expected {} {}, found {}", expected, what, words.len())));
+            }
+            for w in words {
+                let segments: HashSet<char> = w.chars().collect();
+                if w.chars().any(|c| !('a'..='g').contains(&c)) {
+                    problems.push(ParseError::at(line_no, l, w, "expected only the segments a to g"));
+                } else if segments.len() != w.len() {
+                    problems.push(ParseError::at(line_no, l, w, "a segment appears twice"));
+                }
+            }
+        }
+        for (len, digit) in [(2, 1), (3, 7), (4, 4), (7, 8)] {
+            let count = patterns.split_whitespace().filter(|w| w.len() == len).count();
+            if count != 1 {
+                problems.push(ParseError::at(line_no, l, patterns.trim(), format!("expected one pattern with {} segments for the digit {}, found {}", len, digit, count)));
+            }
+        }
+    }
+    problems
+}
+
+// depths have up to 4 digits, the maps are wider
+fn looks_like_digit_grid(lines: &[&str]) -> bool {
+    first_line(lines).len() >= 5 && is_digits(first_line(lines))
+}
+
+// the octopus grid is always 10x10
+fn looks_like_octopuses(lines: &[&str]) -> bool {
+    looks_like_digit_grid(lines) && lines.len() == 10 && lines.iter().all(|l| l.len() == 10)
+}
+
+fn validate_digit_grid(lines: &[&str]) -> Vec<ParseError> {
+    let mut problems = Vec::new();
+    for (line_no, l) in numbered(lines) {
+        for (col, c) in l.chars().enumerate() {
+            if !c.is_ascii_digit() {
+                problems.push(ParseError::new(line_no, col + 1, c, "expected a digit"));
+            }
+        }
+    }
+    check_width(&mut problems, lines, "digits");
+    problems.sort_by_key(|p| (p.line, p.column));
+    problems
+}
+
+fn looks_like_brackets(lines: &[&str]) -> bool {
+    let first = first_line(lines);
+    !first.is_empty() && first.chars().all(is_bracket)
+}
+
+fn validate_brackets(lines: &[&str]) -> Vec<ParseError> {
+    let mut problems = Vec::new();
+    for (line_no, l) in numbered(lines) {
+        if l.is_empty() {
+            problems.push(ParseError::new(line_no, 1, "", "empty line"));
+        }
+        for (col, c) in l.chars().enumerate().filter(|(_, c)| !is_bracket(*c)) {
+            problems.push(ParseError::new(line_no, col + 1, c, "expected a bracket"));
+        }
+    }
+    problems
+}
+
+fn looks_like_caves(lines: &[&str]) -> bool {
+    first_line(lines).split_once('-').is_some_and(|(a, b)| is_cave(a) && is_cave(b))
+}
+
+fn is_cave(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+// a-b passages between named caves, start and end have to be there and two big caves
+// can't be connected or there would be endless paths between them
+fn validate_caves(lines: &[&str]) -> Vec<ParseError> {
+    let mut problems = Vec::new();
+    let mut caves = HashSet::new();
+    for (line_no, l) in numbered(lines) {
+        let Some((a, b)) = l.trim().split_once('-').filter(|(_, b)| !b.contains('-')) else {
+            problems.push(ParseError::new(line_no, 1, l, "expected exactly one '-' between two caves"));
+            continue;
+        };
+        for cave in [a, b] {
+            if !is_cave(cave) {
+                problems.push(ParseError::at(line_no, l, cave, "expected a cave name made of letters"));
+            }
+        }
+        let big = |c: &str| is_cave(c) && c.chars().all(|c| c.is_ascii_uppercase());
+        if big(a) && big(b) {
+            problems.push(ParseError::new(line_no, 1, l, "two big caves are connected, the paths between them never end"));
+        }
+        caves.extend([a, b]);
+    }
+    for cave in ["start", "end"] {
+        if !caves.contains(cave) {
+            problems.push(ParseError::new(lines.len() + 1, 1, "", format!("no passage from or to '{}'", cave)));
+        }
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_every_example() {
+        let examples = [
+            (1, include_str!("../../day01/example")),
+            (2, include_str!("../../day02/example")),
+            (3, include_str!("../../day03/example")),
+            (4, include_str!("../../day04/example")),
+            (5, include_str!("../../day05/example")),
+            (6, include_str!("../../day06/example")),
+            (7, include_str!("../../day07/example")),
+            (8, include_str!("../../day08/example")),
+            (9, include_str!("../../day09/example")),
+            (10, include_str!("../../day10/example")),
+            (11, include_str!("../../day11/example")),
+            (12, include_str!("../../day12/example")),
+        ];
+        for (day, text) in examples {
+            let lines: Vec<&str> = text.lines().collect();
+            let (detected, problems) = &detect(&lines)[0];
+            assert_eq!((*detected, problems.len()), (day, 0), "example of day {}", day);
+        }
+    }
+
+    #[test]
+    fn reports_every_problem() {
+        let lines = ["0,9 -> 5,9", "8,0 -> x,8", "9,4 - 3,4", "1,1 -> 2,2"];
+        assert_eq!(detect(&lines)[0].0, 5);
+        let problems = validate(5, &lines).unwrap();
+        assert_eq!(problems.iter().map(|p| p.line).collect::<Vec<_>>(), vec![2, 3]);
+
+        let board = ["1,2", "", "1 2 3 4 5", "1 2 3 4", "1 2 3 4 5", "1 2 3 4 5", "1 2 3 4 5", "", "1 2 3 4 5"];
+        let problems = validate(4, &board).unwrap();
+        assert_eq!(problems.iter().map(|p| p.line).collect::<Vec<_>>(), vec![4, 9]);
+    }
+}
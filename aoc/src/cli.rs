@@ -0,0 +1,469 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use aoc_common::{InputSource, INPUT_ENV};
+
+use crate::answers::DEFAULT_ANSWERS;
+use crate::config::{parse_setting, Setting};
+use crate::gen::GenOptions;
+use crate::visualize::Viewport;
+
+pub const USAGE: &str = "usage:
+    aoc run --day <N> [--part <1|2>] [--input <path|->] [--format <text|json>] [--stream] [--config <path>] [--set <option=value>]...
+    aoc run --all [--jobs <N>] [--format <text|json>] [--stream] [--config <path>] [--set <dayNN.option=value>]...
+    aoc run --day <5|9|11> --visualize [--part <1|2>] [--delay <ms>] [--viewport <x,y,w,h>]
+    aoc run --day 1 --report [--input <path|->]
+    aoc run --day 1 --monitor [--input <path|->] [--set window=<N>]
+    aoc verify [--answers <path>] [--day <N>]
+    aoc bench --day <N> [--runs <R>] [--input <path|->] [--baseline <path>] [--save <path>]
+    aoc gen --day <N> [--seed <S>] [--size <N>] [--width <W>] [--height <H>] [--output <path>]
+    aoc prop [--day <N>] [--cases <N>] [--seed <S>]
+    aoc conformance [--go-dir <path>] [--cases <N>] [--seed <S>] [--allow-skip]
+    aoc export --day <2|5|9|11|12> --output <path> [--part <1|2>] [--input <path|->] [--scale <N>]
+    aoc repl --day <4|6|11> [--input <path>]
+    aoc check <path|-> [--day <N>]
+    aoc list
+
+-v and -vv work with every command (but not after --) and log the days' debug or trace messages to stderr.
+AOC_LOG sets the level per day or module and wins over them, e.g. AOC_LOG=day08=trace or
+AOC_LOG=info,day11=debug
+--input - reads the puzzle input from stdin. without --input a single day falls back
+to $AOC_INPUT and then to dayNN/input relative to the current directory.
+--all runs the days on N threads (1 by default) and prints a summary table, a failing
+day doesn't stop the others.
+--format json prints one {day, part, answer, elapsed_ns} object per line, failed parts
+have a null answer and an error field instead.
+--stream solves the input line by line while reading it instead of loading it all first, so
+gigabyte inputs fit in memory. days 1, 2, 3 and 10 can do that (day03 and day10 only part 1),
+with --all the other days read their input as usual.
+--set changes one of the puzzle constants, e.g. --set days_part2=100 for day 6. with --all the
+option needs the day in front: --set day06.days_part2=100. --config reads the options from a
+file with a [dayNN] section per day and option = value lines, --set wins over the file.
+aoc list shows every day's options and their defaults.
+--visualize animates day05's lines, day09's basins or day11's octopuses in the terminal, one
+frame every --delay milliseconds (50 by default). --viewport crops big maps, by default the
+top left 100x100 points are shown.
+--report prints day01's depth statistics: increases, decreases and plateaus, the longest run up or
+down, the largest jump, min, max and mean depth and the index of every increase
+--monitor reads day01's depths one at a time and prints the latest depth and window sum and both
+increase counters after each one, e.g. some_sensor | aoc run --day 1 --monitor --input -
+verify checks the solvers against the answers stored in answers.toml
+bench times parse, part1 and part2 over R runs (10 by default) and prints min, median and max.
+--save stores the medians in a baseline file, --baseline compares against one
+gen writes a random but valid input for the day, the same seed always gives the same input.
+--size is the number of lines (boards for day04, fish for day06, crabs for day07, connections
+for day12), --width and --height set the bits per line for day03 and the grid for day09 and day11
+prop checks invariants of the solvers on generated inputs (100 cases by default) and prints
+the smallest failing input it can find
+conformance runs day12 and the go version in day12_go on the same hand-written and generated
+inputs and reports every input where the path counts differ or only one of them fails.
+without go installed it fails, unless --allow-skip is given
+export saves an image of the day's state: the day05 overlap heatmap, the day09 basins and every
+day11 step (out.pgm becomes out_0001.pgm, out_0002.pgm, ...) as .ppm or .pgm with every point
+drawn as a scale x scale square, the day02 trajectory and the day12 cave graph as .svg
+check works out which day an input file is for and lists every problem in it, --day skips
+the guessing and checks the file against that day's format
+repl loads the input and reads commands from stdin to step through day04's draws, day06's days
+or day11's steps: step [k], goto <step>, show, stats, reset, help and quit";
+
+pub enum Command {
+    Run(RunArgs),
+    Verify(VerifyArgs),
+    Bench(BenchArgs),
+    Gen(GenArgs),
+    Prop(PropArgs),
+    Conformance(ConformanceArgs),
+    Export(ExportArgs),
+    Repl(ReplArgs),
+    Check(CheckArgs),
+    List,
+    Help,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+#[derive(Default)]
+pub struct RunArgs {
+    pub day: Option<u32>,
+    pub part: Option<u32>,
+    pub input: Option<InputSource>,
+    pub all: bool,
+    pub jobs: Option<u32>,
+    pub format: Format,
+    pub visualize: bool,
+    pub delay: Option<u64>,
+    pub viewport: Option<Viewport>,
+    pub stream: bool,
+    pub report: bool,
+    pub monitor: bool,
+    pub config: Option<PathBuf>,
+    pub settings: Vec<Setting>,
+}
+
+pub struct VerifyArgs {
+    pub answers: PathBuf,
+    pub day: Option<u32>,
+}
+
+pub struct BenchArgs {
+    pub day: u32,
+    pub runs: u32,
+    pub input: Option<InputSource>,
+    pub baseline: Option<PathBuf>,
+    pub save: Option<PathBuf>,
+}
+
+pub struct GenArgs {
+    pub day: u32,
+    pub seed: u64,
+    pub options: GenOptions,
+    pub output: Option<PathBuf>,
+}
+
+pub struct PropArgs {
+    pub day: Option<u32>,
+    pub cases: u32,
+    pub seed: u64,
+}
+
+pub struct ConformanceArgs {
+    pub go_dir: PathBuf,
+    pub cases: u32,
+    pub seed: u64,
+    // a missing go toolchain exits with success instead of an error
+    pub allow_skip: bool,
+}
+
+pub struct ExportArgs {
+    pub day: u32,
+    pub part: Option<u32>,
+    pub input: Option<InputSource>,
+    pub output: PathBuf,
+    pub scale: Option<usize>,
+}
+
+pub struct ReplArgs {
+    pub day: u32,
+    pub input: Option<InputSource>,
+}
+
+// the flags of every command that take a value, the value after them is never a -v
+const VALUE_FLAGS: [&str; 31] = [
+    "--day", "-d", "--part", "-p", "--input", "-i", "--jobs", "-j", "--config", "--set", "--delay", "--viewport",
+    "--format", "-f", "--answers", "--runs", "-r", "--baseline", "--save", "--seed", "-s", "--size", "-n",
+    "--width", "--height", "--output", "-o", "--cases", "-c", "--go-dir", "--scale",
+];
+
+// -v, -vv and --verbose can go anywhere before a --, except as the value of another flag.
+// the rest is left for parse_args
+pub fn take_verbosity<I: Iterator<Item = String>>(args: I) -> (u8, Vec<String>) {
+    let mut verbosity = 0u8;
+    let mut rest = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--" => {
+                rest.extend(args);
+                break;
+            }
+            "--verbose" => verbosity = verbosity.saturating_add(1),
+            a if a.len() > 1 && a.strip_prefix('-').is_some_and(|v| v.chars().all(|c| c == 'v')) => {
+                verbosity = verbosity.saturating_add(a.len() as u8 - 1);
+            }
+            a if VALUE_FLAGS.contains(&a) => {
+                rest.push(arg);
+                rest.extend(args.next());
+            }
+            _ => rest.push(arg),
+        }
+    }
+    (verbosity, rest)
+}
+
+pub struct CheckArgs {
+    pub input: InputSource,
+    pub day: Option<u32>,
+}
+
+pub fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let command = match args.next() {
+        None => return Ok(Command::Help),
+        Some(c) => c,
+    };
+
+    match command.as_str() {
+        "run" => parse_run_args(args).map(Command::Run),
+        "verify" => parse_verify_args(args).map(Command::Verify),
+        "bench" => parse_bench_args(args).map(Command::Bench),
+        "gen" => parse_gen_args(args).map(Command::Gen),
+        "prop" => parse_prop_args(args).map(Command::Prop),
+        "conformance" => parse_conformance_args(args).map(Command::Conformance),
+        "export" => parse_export_args(args).map(Command::Export),
+        "repl" => parse_repl_args(args).map(Command::Repl),
+        "check" => parse_check_args(args).map(Command::Check),
+        "list" => Ok(Command::List),
+        "help" | "-h" | "--help" => Ok(Command::Help),
+        _ => Err(format!("unknown command '{}'", command)),
+    }
+}
+
+fn parse_run_args<I: Iterator<Item = String>>(mut args: I) -> Result<RunArgs, String> {
+    let mut run_args = RunArgs::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => run_args.day = Some(parse_number(&arg, args.next())?),
+            "--part" | "-p" => run_args.part = Some(parse_number(&arg, args.next())?),
+            "--input" | "-i" => run_args.input = Some(InputSource::parse(&value_for(&arg, args.next())?)),
+            "--all" | "-a" => run_args.all = true,
+            "--jobs" | "-j" => run_args.jobs = Some(parse_number(&arg, args.next())?),
+            "--stream" => run_args.stream = true,
+            "--report" => run_args.report = true,
+            "--monitor" => run_args.monitor = true,
+            "--config" => run_args.config = Some(PathBuf::from(value_for(&arg, args.next())?)),
+            "--set" => run_args.settings.push(parse_setting(&value_for(&arg, args.next())?)?),
+            "--visualize" => run_args.visualize = true,
+            "--delay" => run_args.delay = Some(parse_number(&arg, args.next())?),
+            "--viewport" => run_args.viewport = Some(Viewport::parse(&value_for(&arg, args.next())?)?),
+            "--format" | "-f" => run_args.format = parse_format(&value_for(&arg, args.next())?)?,
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    if run_args.all && (run_args.day.is_some() || run_args.input.is_some()) {
+        return Err("--all can't be combined with --day or --input".to_string());
+    }
+    if !run_args.all && run_args.jobs.is_some() {
+        return Err("--jobs only works together with --all".to_string());
+    }
+    if run_args.jobs == Some(0) {
+        return Err("--jobs has to be at least 1".to_string());
+    }
+    if run_args.visualize && (run_args.all || run_args.format == Format::Json) {
+        return Err("--visualize works on a single day with text output".to_string());
+    }
+    if run_args.report && (run_args.all || run_args.visualize || run_args.stream || run_args.part.is_some()) {
+        return Err("--report can't be combined with --all, --visualize, --stream or --part".to_string());
+    }
+    if run_args.report && (run_args.format == Format::Json || run_args.config.is_some() || !run_args.settings.is_empty()) {
+        return Err("--report prints text and has no options, it can't be combined with --format json, --set or --config".to_string());
+    }
+    if run_args.monitor && (run_args.all || run_args.visualize || run_args.stream || run_args.report || run_args.part.is_some()) {
+        return Err("--monitor can't be combined with --all, --visualize, --stream, --report or --part".to_string());
+    }
+    if run_args.monitor && run_args.format == Format::Json {
+        return Err("--monitor prints text, it can't be combined with --format json".to_string());
+    }
+    if run_args.visualize && run_args.stream {
+        return Err("--visualize and --stream can't be combined".to_string());
+    }
+    if run_args.visualize && (run_args.config.is_some() || !run_args.settings.is_empty()) {
+        return Err("--visualize always uses the puzzle's own constants, it can't be combined with --set or --config".to_string());
+    }
+    if run_args.all && run_args.settings.iter().any(|s| s.day.is_none()) {
+        return Err("with --all every --set needs the day in front, e.g. --set day06.days_part2=100".to_string());
+    }
+    if !run_args.visualize && (run_args.delay.is_some() || run_args.viewport.is_some()) {
+        return Err("--delay and --viewport only work together with --visualize".to_string());
+    }
+    if !run_args.all && run_args.day.is_none() {
+        return Err("either --day or --all is required".to_string());
+    }
+    if !run_args.all && run_args.input.is_none() {
+        if let Ok(v) = std::env::var(INPUT_ENV) {
+            if !v.is_empty() {
+                run_args.input = Some(InputSource::parse(&v));
+            }
+        }
+    }
+    if let Some(part) = run_args.part {
+        if part != 1 && part != 2 {
+            return Err(format!("part has to be 1 or 2, got {}", part));
+        }
+    }
+
+    Ok(run_args)
+}
+
+fn parse_verify_args<I: Iterator<Item = String>>(mut args: I) -> Result<VerifyArgs, String> {
+    let mut verify_args = VerifyArgs { answers: PathBuf::from(DEFAULT_ANSWERS), day: None };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" => verify_args.answers = PathBuf::from(value_for(&arg, args.next())?),
+            "--day" | "-d" => verify_args.day = Some(parse_number(&arg, args.next())?),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    Ok(verify_args)
+}
+
+fn parse_bench_args<I: Iterator<Item = String>>(mut args: I) -> Result<BenchArgs, String> {
+    let mut day = None;
+    let mut bench_args = BenchArgs { day: 0, runs: 10, input: None, baseline: None, save: None };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_number(&arg, args.next())?),
+            "--runs" | "-r" => bench_args.runs = parse_number(&arg, args.next())?,
+            "--input" | "-i" => bench_args.input = Some(InputSource::parse(&value_for(&arg, args.next())?)),
+            "--baseline" => bench_args.baseline = Some(PathBuf::from(value_for(&arg, args.next())?)),
+            "--save" => bench_args.save = Some(PathBuf::from(value_for(&arg, args.next())?)),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    bench_args.day = day.ok_or("--day is required")?;
+    if bench_args.runs == 0 {
+        return Err("--runs has to be at least 1".to_string());
+    }
+
+    Ok(bench_args)
+}
+
+fn parse_gen_args<I: Iterator<Item = String>>(mut args: I) -> Result<GenArgs, String> {
+    let mut day = None;
+    let mut gen_args = GenArgs { day: 0, seed: 1, options: GenOptions::default(), output: None };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_number(&arg, args.next())?),
+            "--seed" | "-s" => gen_args.seed = parse_number(&arg, args.next())?,
+            "--size" | "-n" => gen_args.options.size = Some(parse_number(&arg, args.next())?),
+            "--width" => gen_args.options.width = Some(parse_number(&arg, args.next())?),
+            "--height" => gen_args.options.height = Some(parse_number(&arg, args.next())?),
+            "--output" | "-o" => gen_args.output = Some(PathBuf::from(value_for(&arg, args.next())?)),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    gen_args.day = day.ok_or("--day is required")?;
+
+    Ok(gen_args)
+}
+
+fn parse_prop_args<I: Iterator<Item = String>>(mut args: I) -> Result<PropArgs, String> {
+    let mut prop_args = PropArgs { day: None, cases: 100, seed: 1 };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => prop_args.day = Some(parse_number(&arg, args.next())?),
+            "--cases" | "-c" => prop_args.cases = parse_number(&arg, args.next())?,
+            "--seed" | "-s" => prop_args.seed = parse_number(&arg, args.next())?,
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    Ok(prop_args)
+}
+
+fn parse_conformance_args<I: Iterator<Item = String>>(mut args: I) -> Result<ConformanceArgs, String> {
+    let mut conformance_args = ConformanceArgs { go_dir: PathBuf::from("day12_go"), cases: 100, seed: 1, allow_skip: false };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--go-dir" => conformance_args.go_dir = PathBuf::from(value_for(&arg, args.next())?),
+            "--cases" | "-c" => conformance_args.cases = parse_number(&arg, args.next())?,
+            "--seed" | "-s" => conformance_args.seed = parse_number(&arg, args.next())?,
+            "--allow-skip" => conformance_args.allow_skip = true,
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    Ok(conformance_args)
+}
+
+fn parse_export_args<I: Iterator<Item = String>>(mut args: I) -> Result<ExportArgs, String> {
+    let (mut day, mut output) = (None, None);
+    let mut export_args = ExportArgs { day: 0, part: None, input: None, output: PathBuf::new(), scale: None };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_number(&arg, args.next())?),
+            "--part" | "-p" => export_args.part = Some(parse_number(&arg, args.next())?),
+            "--input" | "-i" => export_args.input = Some(InputSource::parse(&value_for(&arg, args.next())?)),
+            "--output" | "-o" => output = Some(PathBuf::from(value_for(&arg, args.next())?)),
+            "--scale" => export_args.scale = Some(parse_number(&arg, args.next())?),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    export_args.day = day.ok_or("--day is required")?;
+    export_args.output = output.ok_or("--output is required")?;
+    if let Some(part) = export_args.part {
+        if part != 1 && part != 2 {
+            return Err(format!("part has to be 1 or 2, got {}", part));
+        }
+    }
+    if export_args.scale == Some(0) {
+        return Err("--scale has to be at least 1".to_string());
+    }
+
+    Ok(export_args)
+}
+
+fn parse_repl_args<I: Iterator<Item = String>>(mut args: I) -> Result<ReplArgs, String> {
+    let mut day = None;
+    let mut input = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_number(&arg, args.next())?),
+            "--input" | "-i" => input = Some(InputSource::parse(&value_for(&arg, args.next())?)),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    // stdin is where the commands come from
+    if input == Some(InputSource::Stdin) {
+        return Err("repl reads its commands from stdin, the input has to come from a file".to_string());
+    }
+    Ok(ReplArgs { day: day.ok_or("--day is required")?, input })
+}
+
+fn parse_check_args<I: Iterator<Item = String>>(mut args: I) -> Result<CheckArgs, String> {
+    let mut input = None;
+    let mut day = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_number(&arg, args.next())?),
+            a if input.is_none() && (a == "-" || !a.starts_with('-')) => input = Some(InputSource::parse(a)),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    Ok(CheckArgs { input: input.ok_or("check needs the file to check")?, day })
+}
+
+fn parse_format(value: &str) -> Result<Format, String> {
+    match value {
+        "text" => Ok(Format::Text),
+        "json" => Ok(Format::Json),
+        _ => Err(format!("unknown format '{}', expected text or json", value)),
+    }
+}
+
+fn value_for(flag: &str, value: Option<String>) -> Result<String, String> {
+    value.ok_or(format!("missing value for {}", flag))
+}
+
+fn parse_number<T: FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    let value = value_for(flag, value)?;
+    value.parse::<T>().map_err(|_| format!("expected a number for {}, got '{}'", flag, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn verbosity_leaves_flag_values_alone() {
+        let (verbosity, rest) = take_verbosity(strings(&["-v", "run", "--set", "-v", "-vv", "--day", "1"]).into_iter());
+        assert_eq!((verbosity, rest), (3, strings(&["run", "--set", "-v", "--day", "1"])));
+
+        let (verbosity, rest) = take_verbosity(strings(&["check", "--verbose", "--", "-v"]).into_iter());
+        assert_eq!((verbosity, rest), (1, strings(&["check", "-v"])));
+    }
+}
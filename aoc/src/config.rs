@@ -0,0 +1,94 @@
+use aoc_common::{parse_token, ParseError};
+
+// one option of one day, from the config file or --set. None applies to the day that is run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Setting {
+    pub day: Option<u32>,
+    pub key: String,
+    pub value: String,
+}
+
+// same toml subset as answers.toml, one section per day:
+//
+//     [day06]
+//     days_part2 = 100
+//     error_scores = "3,57,1197,25137"
+pub fn parse_config(text: &str) -> Result<Vec<Setting>, ParseError> {
+    let mut settings = Vec::new();
+    let mut day = None;
+
+    for (i, raw_line) in text.lines().enumerate() {
+        let line_no = i + 1;
+        let line = raw_line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[') {
+            let header = header.strip_suffix(']')
+                .ok_or_else(|| ParseError::at(line_no, raw_line, line, "missing ']' after section name"))?
+                .trim();
+            let day_str = header.strip_prefix("day")
+                .ok_or_else(|| ParseError::at(line_no, raw_line, header, "expected [dayNN]"))?;
+            day = Some(parse_token::<u32>(line_no, raw_line, day_str)?);
+            continue;
+        }
+
+        let day = day.ok_or_else(|| ParseError::at(line_no, raw_line, line, "option outside of a [dayNN] section"))?;
+        let (key, value) = line.split_once('=')
+            .ok_or_else(|| ParseError::at(line_no, raw_line, line, "expected 'option = value'"))?;
+        settings.push(Setting { day: Some(day), key: key.trim().to_string(), value: unquote(value.trim()).to_string() });
+    }
+
+    Ok(settings)
+}
+
+// day06.days_part2=100 or just days_part2=100
+pub fn parse_setting(arg: &str) -> Result<Setting, String> {
+    let (name, value) = arg.split_once('=').ok_or_else(|| format!("expected [dayNN.]option=value, got '{}'", arg))?;
+    let (day, key) = match name.split_once('.') {
+        Some((day, key)) => {
+            let day = day.strip_prefix("day").and_then(|d| d.parse().ok())
+                .ok_or_else(|| format!("expected dayNN before the option name, got '{}'", day))?;
+            (Some(day), key)
+        }
+        None => (None, name),
+    };
+    Ok(Setting { day, key: key.trim().to_string(), value: unquote(value.trim()).to_string() })
+}
+
+// the settings that apply to the day in the order they were given, later ones win
+pub fn settings_for(day: u32, settings: &[Setting]) -> Vec<(String, String)> {
+    settings.iter()
+        .filter(|s| s.day.is_none_or(|d| d == day))
+        .map(|s| (s.key.clone(), s.value.clone()))
+        .collect()
+}
+
+fn unquote(value: &str) -> &str {
+    value.strip_prefix('"').and_then(|v| v.strip_suffix('"')).unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_config_sections() {
+        let text = "# variants\n[day06]\ndays_part2 = 100\n\n[day10]\nerror_scores = \"1,2,3,4\"\n";
+        let settings = parse_config(text).unwrap();
+        assert_eq!(settings_for(6, &settings), vec![("days_part2".to_string(), "100".to_string())]);
+        assert_eq!(settings_for(10, &settings), vec![("error_scores".to_string(), "1,2,3,4".to_string())]);
+
+        let e = parse_config("days_part2 = 1\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+    }
+
+    #[test]
+    fn parses_set_arguments() {
+        assert_eq!(parse_setting("day11.step_limit=50"), Ok(Setting { day: Some(11), key: "step_limit".to_string(), value: "50".to_string() }));
+        assert_eq!(parse_setting("board_size=3").unwrap().day, None);
+        assert!(parse_setting("board_size").is_err());
+        assert!(parse_setting("d4.board_size=3").is_err());
+    }
+}
@@ -0,0 +1,191 @@
+use std::fmt::{self, Display, Formatter};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+
+use aoc_common::{Rng, Solution};
+use day12::Day12;
+
+use crate::cli::ConformanceArgs;
+use crate::gen::{generate, GenOptions};
+
+// what one implementation made of an input: both path counts or a rejection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Counts(usize, usize),
+    Rejected(String),
+}
+
+impl Outcome {
+    // both rejecting counts as agreement, the error messages differ between the languages
+    pub fn agrees_with(&self, other: &Outcome) -> bool {
+        match (self, other) {
+            (Outcome::Counts(a1, a2), Outcome::Counts(b1, b2)) => a1 == b1 && a2 == b2,
+            (Outcome::Rejected(_), Outcome::Rejected(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Counts(p1, p2) => write!(f, "{} / {} paths", p1, p2),
+            Outcome::Rejected(e) => write!(f, "rejected: {}", e),
+        }
+    }
+}
+
+// the puzzle examples, malformed inputs that both versions have to refuse and the inputs the
+// two read differently: go takes a cave as big when only its first letter is upper case, it
+// panics on an empty name and it doesn't trim carriage returns or trailing spaces
+const HAND_WRITTEN: [(&str, &str); 10] = [
+    ("example", include_str!("../../day12/example")),
+    ("example_2", include_str!("../../day12/example_2")),
+    ("example_3", include_str!("../../day12/example_3")),
+    ("two dashes", "start-A-end\n"),
+    ("no dash", "start-A\nA\nA-end\n"),
+    ("empty line", "start-A\n\nA-end\n"),
+    ("mixed case name", "start-Ab\nAb-c\nAb-end\n"),
+    ("empty name", "start-\nstart-A\nA-end\n"),
+    ("crlf line endings", "start-A\r\nA-b\r\nA-end\r\n"),
+    ("trailing spaces", "start-A \nA-b  \nA-end\n"),
+];
+
+pub fn rust_outcome(input: &str) -> Outcome {
+    let caves = match Day12::parse(input) {
+        Ok(c) => c,
+        Err(e) => return Outcome::Rejected(e.to_string()),
+    };
+    match (Day12::part1(&caves), Day12::part2(&caves)) {
+        (Ok(p1), Ok(p2)) => Outcome::Counts(p1, p2),
+        (Err(e), _) | (_, Err(e)) => Outcome::Rejected(e.to_string()),
+    }
+}
+
+// the go version always reads ./input, so every case runs in its own scratch directory
+pub struct GoDay12 {
+    binary: PathBuf,
+    workdir: PathBuf,
+}
+
+impl GoDay12 {
+    // Ok(None) when there's no go toolchain to build with
+    pub fn build(source_dir: &Path, workdir: &Path) -> Result<Option<GoDay12>, String> {
+        std::fs::create_dir_all(workdir).map_err(|e| format!("failed to create {}: {}", workdir.display(), e))?;
+        let binary = workdir.join("day12_go");
+        let output = match Command::new("go").arg("build").arg("-o").arg(&binary).current_dir(source_dir).output() {
+            Ok(o) => o,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(format!("failed to run go build: {}", e)),
+        };
+        if !output.status.success() {
+            return Err(format!("go build failed: {}", String::from_utf8_lossy(&output.stderr).trim()));
+        }
+        Ok(Some(GoDay12 { binary, workdir: workdir.to_path_buf() }))
+    }
+
+    pub fn outcome(&self, input: &str) -> Result<Outcome, String> {
+        let input_path = self.workdir.join("input");
+        std::fs::write(&input_path, input).map_err(|e| format!("failed to write {}: {}", input_path.display(), e))?;
+        let output = Command::new(&self.binary).current_dir(&self.workdir).output()
+            .map_err(|e| format!("failed to run {}: {}", self.binary.display(), e))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Ok(Outcome::Rejected(stderr.lines().next().unwrap_or("exited with an error").to_string()));
+        }
+        parse_go_output(&String::from_utf8_lossy(&output.stdout))
+    }
+}
+
+// number of paths part 1: 10
+// number of paths part 2: 36
+pub fn parse_go_output(stdout: &str) -> Result<Outcome, String> {
+    let count = |part: u32| {
+        let prefix = format!("number of paths part {}: ", part);
+        stdout.lines()
+            .find_map(|l| l.strip_prefix(&prefix))
+            .and_then(|n| n.trim().parse::<usize>().ok())
+            .ok_or_else(|| format!("no count for part {} in go output '{}'", part, stdout.trim()))
+    };
+    Ok(Outcome::Counts(count(1)?, count(2)?))
+}
+
+pub fn conformance(args: &ConformanceArgs) -> ExitCode {
+    let workdir = std::env::temp_dir().join(format!("aoc-day12-conformance-{}", std::process::id()));
+    let go = match GoDay12::build(&args.go_dir, &workdir) {
+        Ok(Some(go)) => go,
+        Ok(None) => {
+            let _ = std::fs::remove_dir_all(&workdir);
+            if args.allow_skip {
+                println!("skipped: go is not installed, nothing to compare day12 against");
+                return ExitCode::SUCCESS;
+            }
+            eprintln!("error: go is not installed, nothing to compare day12 against (--allow-skip makes this a success)");
+            return ExitCode::FAILURE;
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut corpus: Vec<(String, String)> = HAND_WRITTEN.iter().map(|(name, input)| (name.to_string(), input.to_string())).collect();
+    for case in 0..args.cases {
+        let seed = args.seed.wrapping_add(case as u64);
+        let mut rng = Rng::new(seed);
+        let opts = GenOptions { size: Some(rng.range(1, 16) as usize), ..GenOptions::default() };
+        let input = generate(12, &opts, &mut rng).expect("day12 has a generator");
+        corpus.push((format!("generated, seed {}", seed), input));
+    }
+
+    let mut disagreements = 0;
+    for (name, input) in &corpus {
+        let rust = rust_outcome(input);
+        let go = match go.outcome(input) {
+            Ok(o) => o,
+            Err(e) => {
+                eprintln!("error: {}: {}", name, e);
+                disagreements += 1;
+                continue;
+            }
+        };
+        if !rust.agrees_with(&go) {
+            println!("DIFFERS {}\n  rust: {}\n  go:   {}\n{}", name, rust, go, input);
+            disagreements += 1;
+        }
+    }
+    let _ = std::fs::remove_dir_all(&workdir);
+
+    println!("{} inputs compared, {} disagreements", corpus.len(), disagreements);
+    if disagreements > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rust_side_of_the_hand_written_corpus() {
+        let outcomes: Vec<Outcome> = HAND_WRITTEN.iter().map(|(_, input)| rust_outcome(input)).collect();
+        assert_eq!(outcomes[..3], [Outcome::Counts(10, 36), Outcome::Counts(19, 103), Outcome::Counts(226, 3509)]);
+        assert!(outcomes[3..6].iter().all(|o| matches!(o, Outcome::Rejected(_))));
+        // Ab is a small cave here, the empty name a big one and the line endings get trimmed
+        assert_eq!(outcomes[6..], [Outcome::Counts(1, 2), Outcome::Counts(1, 1), Outcome::Counts(2, 3), Outcome::Counts(2, 3)]);
+    }
+
+    #[test]
+    fn reads_go_output() {
+        let out = "number of paths part 1: 10\nnumber of paths part 2: 36\n";
+        assert_eq!(parse_go_output(out), Ok(Outcome::Counts(10, 36)));
+        assert!(parse_go_output("number of paths part 1: 10\n").is_err());
+
+        let rejected = Outcome::Rejected("bad line".to_string());
+        assert!(rejected.agrees_with(&Outcome::Rejected("other message".to_string())));
+        assert!(!rejected.agrees_with(&Outcome::Counts(10, 36)));
+    }
+}
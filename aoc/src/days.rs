@@ -0,0 +1,108 @@
+use std::path::PathBuf;
+
+use aoc_common::{run_parts, stream_parts, DayRun, InputSource, Options, Solution, Streaming};
+
+// option name and value pairs of one day, e.g. ("days_part2", "100")
+pub type Settings = [(String, String)];
+
+pub type RunFn = fn(&str, &InputSource, &[u32], &Settings) -> DayRun;
+pub type OptionsFn = fn(&Settings) -> Result<Vec<(&'static str, String)>, String>;
+// reads the input itself, line by line
+pub type StreamFn = fn(&InputSource, &[u32], &Settings) -> DayRun;
+
+pub struct Day {
+    pub day: u32,
+    pub title: &'static str,
+    pub run: RunFn,
+    pub options: OptionsFn,
+    pub stream: Option<StreamFn>,
+    pub streamed_parts: &'static [u32],
+}
+
+impl Day {
+    const fn of<S: Solution>() -> Day {
+        Day { day: S::DAY, title: S::TITLE, run: run_with::<S>, options: option_values::<S>, stream: None, streamed_parts: &[] }
+    }
+
+    const fn streaming<S: Streaming>() -> Day {
+        Day { stream: Some(stream_with::<S>), streamed_parts: S::STREAMED_PARTS, ..Day::of::<S>() }
+    }
+
+    pub fn can_stream(&self, parts: &[u32]) -> bool {
+        self.stream.is_some() && parts.iter().all(|p| self.streamed_parts.contains(p))
+    }
+
+    // every day keeps its puzzle input next to its Cargo.toml
+    pub fn default_input(&self) -> InputSource {
+        InputSource::File(PathBuf::from(format!("day{:02}", self.day)).join(aoc_common::DEFAULT_INPUT))
+    }
+
+    // runs with the puzzle's own constants
+    pub fn run(&self, input: &str, source: &InputSource, parts: &[u32]) -> DayRun {
+        (self.run)(input, source, parts, &[])
+    }
+
+    pub fn run_with(&self, input: &str, source: &InputSource, parts: &[u32], settings: &Settings) -> DayRun {
+        (self.run)(input, source, parts, settings)
+    }
+
+    // checks the settings and returns every option of the day with the settings applied
+    pub fn options(&self, settings: &Settings) -> Result<Vec<(&'static str, String)>, String> {
+        (self.options)(settings)
+    }
+
+    pub fn solve(&self, part: u32, input: &str, source: &InputSource) -> Result<String, String> {
+        let mut day_run = self.run(input, source, &[part]);
+        day_run.parts.remove(0).answer
+    }
+}
+
+pub(crate) fn options_from<S: Solution>(settings: &Settings) -> Result<S::Options, String> {
+    let mut options = S::Options::default();
+    for (key, value) in settings {
+        options.set(key, value)?;
+    }
+    options.validate()?;
+    Ok(options)
+}
+
+fn run_with<S: Solution>(input: &str, source: &InputSource, parts: &[u32], settings: &Settings) -> DayRun {
+    match options_from::<S>(settings) {
+        Ok(options) => run_parts::<S>(input, source, parts, &options),
+        Err(e) => DayRun::failed(parts, &e),
+    }
+}
+
+fn stream_with<S: Streaming>(source: &InputSource, parts: &[u32], settings: &Settings) -> DayRun {
+    let options = match options_from::<S>(settings) {
+        Ok(o) => o,
+        Err(e) => return DayRun::failed(parts, &e),
+    };
+    match source.reader() {
+        Ok(reader) => stream_parts::<S, _>(reader, source, parts, &options),
+        Err(e) => DayRun::failed(parts, &format!("failed to read {}: {}", source, e)),
+    }
+}
+
+fn option_values<S: Solution>(settings: &Settings) -> Result<Vec<(&'static str, String)>, String> {
+    options_from::<S>(settings).map(|o| o.values())
+}
+
+pub const DAYS: [Day; 12] = [
+    Day::streaming::<day01::Day01>(),
+    Day::streaming::<day02::Day02>(),
+    Day::streaming::<day03::Day03>(),
+    Day::of::<day04::Day04>(),
+    Day::of::<day05::Day05>(),
+    Day::of::<day06::Day06>(),
+    Day::of::<day07::Day07>(),
+    Day::of::<day08::Day08>(),
+    Day::of::<day09::Day09>(),
+    Day::streaming::<day10::Day10>(),
+    Day::of::<day11::Day11>(),
+    Day::of::<day12::Day12>(),
+];
+
+pub fn find_day(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
@@ -0,0 +1,228 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aoc_common::{Grid, Solution};
+
+use crate::cli::ExportArgs;
+use crate::days::Day;
+use crate::image::{heat, palette, Raster, Rgb, Svg};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImageFormat {
+    Ppm,
+    Pgm,
+    Svg,
+}
+
+impl ImageFormat {
+    fn of(path: &Path) -> Result<ImageFormat, String> {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("ppm") => Ok(ImageFormat::Ppm),
+            Some("pgm") => Ok(ImageFormat::Pgm),
+            Some("svg") => Ok(ImageFormat::Svg),
+            _ => Err(format!("can't tell the image format of {}, use .ppm, .pgm or .svg", path.display())),
+        }
+    }
+}
+
+pub fn export(day: &Day, args: &ExportArgs) -> ExitCode {
+    let source = args.input.clone().unwrap_or_else(|| day.default_input());
+    let result = source.read()
+        .map_err(|e| format!("failed to read {}: {}", source, e))
+        .and_then(|input| {
+            let format = ImageFormat::of(&args.output)?;
+            match (day.day, format) {
+                (2, ImageFormat::Svg) => day02_trajectory(&input, args),
+                (12, ImageFormat::Svg) => day12_graph(&input, args),
+                (5, ImageFormat::Ppm | ImageFormat::Pgm) => day05_heatmap(&input, args, format),
+                (9, ImageFormat::Ppm | ImageFormat::Pgm) => day09_basins(&input, args, format),
+                (11, ImageFormat::Ppm | ImageFormat::Pgm) => day11_frames(&input, args, format),
+                (2 | 12, _) => Err(format!("day {} is exported as .svg", day.day)),
+                (5 | 9 | 11, _) => Err(format!("day {} is exported as .ppm or .pgm", day.day)),
+                _ => Err(format!("day {} has no export, only days 2, 5, 9, 11 and 12 have one", day.day)),
+            }
+        });
+
+    match result {
+        Ok(written) => {
+            println!("wrote {}", written);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn save(path: &Path, bytes: &[u8]) -> Result<(), String> {
+    std::fs::write(path, bytes).map_err(|e| format!("failed to write {}: {}", path.display(), e))
+}
+
+fn save_raster(path: &Path, raster: &Raster, format: ImageFormat) -> Result<(), String> {
+    match format {
+        ImageFormat::Pgm => save(path, &raster.to_pgm()),
+        _ => save(path, &raster.to_ppm()),
+    }
+}
+
+// black where no line goes, the more lines overlap the hotter the point
+fn day05_heatmap(input: &str, args: &ExportArgs, format: ImageFormat) -> Result<String, String> {
+    let lines = day05::Day05::parse(input).map_err(|e| e.to_string())?;
+    let mut map = day05::Map::new(day05::MAP_SIDE);
+    for line in lines.iter().filter(|l| args.part != Some(1) || l.is_horizontal() || l.is_vertical()) {
+        map.mark_line(line);
+    }
+
+    let field = map.field();
+    let max = field.iter().copied().max().unwrap_or(0).max(1);
+    let raster = Raster::from_cells(field.width(), field.height(), args.scale.unwrap_or(1), |x, y| {
+        heat(field.get(x, y).map_or(0.0, |n| *n as f64 / max as f64))
+    });
+    save_raster(&args.output, &raster, format)?;
+    Ok(args.output.display().to_string())
+}
+
+// every basin in its own colour, brighter the higher it gets, the walls of 9s stay dark
+fn day09_basins(input: &str, args: &ExportArgs, format: ImageFormat) -> Result<String, String> {
+    let map = day09::Day09::parse(input).map_err(|e| e.to_string())?;
+    let heights = map.heights();
+    let mut basin_of: Grid<Option<usize>> = heights.map(|_| None);
+    for (i, min) in day09::find_local_minimums(&map).iter().enumerate() {
+        for c in day09::find_basin(&map, min, day09::WALL_HEIGHT) {
+            let (x, y) = c.position();
+            if let Some(b) = basin_of.get_mut(x, y) {
+                *b = Some(i);
+            }
+        }
+    }
+
+    let raster = Raster::from_cells(heights.width(), heights.height(), args.scale.unwrap_or(4), |x, y| {
+        let h = heights.get(x, y).copied().unwrap_or(9);
+        match basin_of.get(x, y).copied().flatten() {
+            Some(b) => shade(palette(b), 0.4 + 0.6 * h as f64 / 9.0),
+            None if h == 9 => [30, 30, 30],
+            None => shade([255, 255, 255], h as f64 / 9.0),
+        }
+    });
+    save_raster(&args.output, &raster, format)?;
+    Ok(args.output.display().to_string())
+}
+
+// one image per step, flashing octopuses light up. stops when all flash at once, after 100 steps for part 1
+fn day11_frames(input: &str, args: &ExportArgs, format: ImageFormat) -> Result<String, String> {
+    let energies = day11::Day11::parse(input).map_err(|e| e.to_string())?;
+    let mut map = day11::Map::new(&energies);
+    let steps = if args.part == Some(1) { 100 } else { 10000 };
+
+    let mut written = 0;
+    for _ in 0..steps {
+        let all_flashed = map.step();
+        let energies = map.energies();
+        let raster = Raster::from_cells(energies.width(), energies.height(), args.scale.unwrap_or(10), |x, y| {
+            match energies.get(x, y).copied().unwrap_or(0) {
+                0 => [255, 220, 40],
+                e => shade([120, 140, 255], e as f64 / 10.0),
+            }
+        });
+        save_raster(&frame_path(&args.output, map.step), &raster, format)?;
+        written += 1;
+        if all_flashed {
+            break;
+        }
+    }
+    Ok(format!("{} frames, {} to {}", written, frame_path(&args.output, 1).display(), frame_path(&args.output, map.step).display()))
+}
+
+// out.pgm becomes out_0001.pgm, out_0002.pgm, ...
+fn frame_path(path: &Path, step: u32) -> PathBuf {
+    let stem = path.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+    let ext = path.extension().map(|e| e.to_string_lossy().to_string()).unwrap_or_default();
+    path.with_file_name(format!("{}_{:04}.{}", stem, step, ext))
+}
+
+fn shade([r, g, b]: Rgb, brightness: f64) -> Rgb {
+    let f = |c: u8| (c as f64 * brightness.clamp(0.0, 1.0)) as u8;
+    [f(r), f(g), f(b)]
+}
+
+const SVG_MARGIN: f64 = 40.0;
+
+// horizontal position against depth after every command, depth grows downwards like in the sea
+fn day02_trajectory(input: &str, args: &ExportArgs) -> Result<String, String> {
+    let commands = day02::Day02::parse(input).map_err(|e| e.to_string())?;
+    let part = args.part.unwrap_or(2);
+    // one submarine moved through the commands, its position is taken after every one of them
+    let position = |s: &day02::Submarine| if part == 1 { s.part1 } else { s.part2 };
+    let mut submarine = day02::Submarine::default();
+    let mut points: Vec<(i64, i64)> = vec![(0, 0)];
+    for command in &commands {
+        submarine.apply(command);
+        let p = position(&submarine);
+        points.push((p.forward, p.depth));
+    }
+
+    let (width, height) = (800.0, 400.0);
+    let max_forward = points.iter().map(|p| p.0).max().unwrap_or(0).max(1) as f64;
+    let min_depth = points.iter().map(|p| p.1).min().unwrap_or(0).min(0) as f64;
+    let max_depth = points.iter().map(|p| p.1).max().unwrap_or(0).max(1) as f64;
+    let to_svg = |(f, d): (i64, i64)| (
+        SVG_MARGIN + f as f64 / max_forward * (width - 2.0 * SVG_MARGIN),
+        SVG_MARGIN + (d as f64 - min_depth) / (max_depth - min_depth) * (height - 2.0 * SVG_MARGIN),
+    );
+
+    let mut svg = Svg::new(width, height);
+    let surface = SVG_MARGIN - min_depth / (max_depth - min_depth) * (height - 2.0 * SVG_MARGIN);
+    svg.line((SVG_MARGIN, surface), (width - SVG_MARGIN, surface), "stroke:#8ab;stroke-dasharray:4");
+    svg.polyline(&points.iter().map(|p| to_svg(*p)).collect::<Vec<_>>(), "fill:none;stroke:#036;stroke-width:2");
+    let last = points.last().copied().unwrap_or((0, 0));
+    svg.circle(to_svg(last), 4.0, "fill:#c30");
+    svg.text((SVG_MARGIN, 24.0), &format!("part {}: forward {}, depth {}", part, last.0, last.1), "font-family:sans-serif;font-size:14px");
+    save(&args.output, svg.finish().as_bytes())?;
+    Ok(args.output.display().to_string())
+}
+
+// caves on a circle with start first, big caves are drawn bigger
+fn day12_graph(input: &str, args: &ExportArgs) -> Result<String, String> {
+    let connections = day12::Day12::parse(input).map_err(|e| e.to_string())?;
+    let mut caves = connections.caves();
+    caves.sort_by_key(|c| (*c != "start", *c == "end"));
+
+    let (size, radius) = (600.0, 240.0);
+    let position = |cave: &str| {
+        let i = caves.iter().position(|c| *c == cave).unwrap_or(0);
+        let angle = std::f64::consts::PI + i as f64 / caves.len().max(1) as f64 * std::f64::consts::TAU;
+        (size / 2.0 + radius * angle.cos(), size / 2.0 + radius * angle.sin())
+    };
+
+    let mut svg = Svg::new(size, size);
+    for (from, to) in connections.edges() {
+        svg.line(position(from), position(to), "stroke:#999;stroke-width:1.5");
+    }
+    for cave in &caves {
+        let big = cave.chars().all(|c| c.is_ascii_uppercase());
+        let fill = match *cave {
+            "start" => "#3a3",
+            "end" => "#c33",
+            _ if big => "#e90",
+            _ => "#37b",
+        };
+        let (x, y) = position(cave);
+        svg.circle((x, y), if big { 22.0 } else { 16.0 }, &format!("fill:{};stroke:#333", fill));
+        svg.text((x, y + 4.0), cave, "font-family:sans-serif;font-size:11px;text-anchor:middle;fill:white");
+    }
+    save(&args.output, svg.finish().as_bytes())?;
+    Ok(args.output.display().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frames_are_numbered_before_the_extension() {
+        assert_eq!(frame_path(Path::new("out/octopus.pgm"), 7), PathBuf::from("out/octopus_0007.pgm"));
+        assert_eq!(ImageFormat::of(Path::new("a.svg")), Ok(ImageFormat::Svg));
+        assert!(ImageFormat::of(Path::new("a.png")).is_err());
+    }
+}
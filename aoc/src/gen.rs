@@ -0,0 +1,315 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use aoc_common::Rng;
+
+// knobs for the generators, whatever is None falls back to a size close to the real puzzle input
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GenOptions {
+    // number of lines, boards, fish, crabs or cave connections depending on the day
+    pub size: Option<usize>,
+    // bits per line for day03, grid width for day09 and day11
+    pub width: Option<usize>,
+    pub height: Option<usize>,
+}
+
+pub fn generate(day: u32, opts: &GenOptions, rng: &mut Rng) -> Result<String, String> {
+    if opts.size == Some(0) || opts.width == Some(0) || opts.height == Some(0) {
+        return Err("--size, --width and --height have to be at least 1".to_string());
+    }
+    if opts.width.is_some() && ![3, 9, 11].contains(&day) {
+        return Err(format!("--width only works for days 3, 9 and 11, not day {}", day));
+    }
+    if opts.height.is_some() && ![9, 11].contains(&day) {
+        return Err(format!("--height only works for days 9 and 11, not day {}", day));
+    }
+    if opts.size.is_some() && [9, 11].contains(&day) {
+        return Err(format!("day {} is a grid, set its size with --width and --height", day));
+    }
+    let size = |default: usize| opts.size.unwrap_or(default);
+    let width = |default: usize| opts.width.unwrap_or(default);
+    let height = |default: usize| opts.height.unwrap_or(default);
+
+    match day {
+        1 => Ok(depths(size(2000), rng)),
+        2 => Ok(commands(size(1000), rng)),
+        3 => bit_strings(size(1000), width(12), rng),
+        4 => Ok(bingo(size(100), rng)),
+        5 => Ok(vents(size(500), rng)),
+        6 => Ok(comma_separated(size(300), 1, 6, rng)),
+        7 => Ok(comma_separated(size(1000), 0, 2000, rng)),
+        8 => Ok(seven_segments(size(200), rng)),
+        9 => Ok(heightmap(width(100), height(100), rng)),
+        10 => Ok(brackets(size(100), rng)),
+        11 => Ok(digit_grid(width(10), height(10), rng)),
+        12 => Ok(caves(size(20), rng)),
+        _ => Err(format!("no generator for day {}", day)),
+    }
+}
+
+// a sonar sweep drifting slowly downwards
+fn depths(n: usize, rng: &mut Rng) -> String {
+    let mut depth = rng.range(100, 200);
+    let mut out = String::new();
+    for _ in 0..n {
+        writeln!(out, "{}", depth).unwrap_or_default();
+        depth = (depth + rng.range(-10, 21)).max(0);
+    }
+    out
+}
+
+fn commands(n: usize, rng: &mut Rng) -> String {
+    let mut depth = 0;
+    let mut out = String::new();
+    for _ in 0..n {
+        let amount = rng.range(1, 10);
+        // never surface above the water
+        let command = match rng.below(3) {
+            0 if depth >= amount => {
+                depth -= amount;
+                "up"
+            }
+            1 => {
+                depth += amount;
+                "down"
+            }
+            _ => "forward",
+        };
+        writeln!(out, "{} {}", command, amount).unwrap_or_default();
+    }
+    out
+}
+
+// unique lines, otherwise part 2 could end up with identical candidates that can't be told apart
+fn bit_strings(n: usize, width: usize, rng: &mut Rng) -> Result<String, String> {
+    if width == 0 || width > 32 {
+        return Err(format!("width has to be between 1 and 32, got {}", width));
+    }
+    if (n as u64) > 1u64 << width {
+        return Err(format!("can't make {} different lines of {} bits", n, width));
+    }
+    let mut seen = HashSet::new();
+    let mut out = String::new();
+    while seen.len() < n {
+        let v = rng.below(1u64 << width);
+        if seen.insert(v) {
+            writeln!(out, "{:0width$b}", v, width = width).unwrap_or_default();
+        }
+    }
+    Ok(out)
+}
+
+// every number gets drawn, so every board wins eventually
+fn bingo(boards: usize, rng: &mut Rng) -> String {
+    let mut draws: Vec<u32> = (0..100).collect();
+    rng.shuffle(&mut draws);
+    let mut out = draws.iter().map(|d| d.to_string()).collect::<Vec<_>>().join(",");
+    out.push('\n');
+
+    for _ in 0..boards {
+        let mut numbers: Vec<u32> = (0..100).collect();
+        rng.shuffle(&mut numbers);
+        out.push('\n');
+        for row in numbers[..25].chunks(5) {
+            let row: Vec<String> = row.iter().map(|n| format!("{:>2}", n)).collect();
+            writeln!(out, "{}", row.join(" ")).unwrap_or_default();
+        }
+    }
+    out
+}
+
+// horizontal, vertical and 45 degree lines inside the map
+fn vents(n: usize, rng: &mut Rng) -> String {
+    let side = day05::MAP_SIDE as i64;
+    let mut out = String::new();
+    for _ in 0..n {
+        let (x1, y1) = (rng.range(0, side), rng.range(0, side));
+        let len = rng.range(1, side / 2);
+        let (dx, dy) = *rng.pick(&[(1, 0), (-1, 0), (0, 1), (0, -1), (1, 1), (1, -1), (-1, 1), (-1, -1)]);
+        // shorten the line until it fits on the map
+        let fits = |l: i64| (0..side).contains(&(x1 + dx * l)) && (0..side).contains(&(y1 + dy * l));
+        let len = (0..=len).rev().find(|l| fits(*l)).unwrap_or(0);
+        writeln!(out, "{},{} -> {},{}", x1, y1, x1 + dx * len, y1 + dy * len).unwrap_or_default();
+    }
+    out
+}
+
+fn comma_separated(n: usize, lo: i64, hi: i64, rng: &mut Rng) -> String {
+    let values: Vec<String> = (0..n).map(|_| rng.range(lo, hi).to_string()).collect();
+    values.join(",") + "\n"
+}
+
+const DIGIT_SEGMENTS: [&str; 10] = ["abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg"];
+
+// every line gets its own wiring, patterns and letters within them are shuffled
+fn seven_segments(n: usize, rng: &mut Rng) -> String {
+    let mut out = String::new();
+    for _ in 0..n {
+        let mut wiring: Vec<char> = "abcdefg".chars().collect();
+        rng.shuffle(&mut wiring);
+
+        let mut patterns: Vec<String> = DIGIT_SEGMENTS.iter().map(|s| scramble(s, &wiring, rng)).collect();
+        rng.shuffle(&mut patterns);
+        let outputs: Vec<String> = (0..4).map(|_| {
+            let digit = rng.pick(&DIGIT_SEGMENTS);
+            scramble(digit, &wiring, rng)
+        }).collect();
+        writeln!(out, "{} | {}", patterns.join(" "), outputs.join(" ")).unwrap_or_default();
+    }
+    out
+}
+
+fn scramble(segments: &str, wiring: &[char], rng: &mut Rng) -> String {
+    let mut wires: Vec<char> = segments.bytes().map(|s| wiring[(s - b'a') as usize]).collect();
+    rng.shuffle(&mut wires);
+    wires.into_iter().collect()
+}
+
+// walls of 9s split the map into basins, everything else is random
+fn heightmap(width: usize, height: usize, rng: &mut Rng) -> String {
+    let mut out = String::new();
+    for _ in 0..height {
+        let row: String = (0..width).map(|_| if rng.chance(0.25) { '9' } else { char::from(b'0' + rng.below(9) as u8) }).collect();
+        writeln!(out, "{}", row).unwrap_or_default();
+    }
+    out
+}
+
+// a random grid is a valid input, but it isn't guaranteed to ever flash all at once for part 2
+fn digit_grid(width: usize, height: usize, rng: &mut Rng) -> String {
+    let mut out = String::new();
+    for _ in 0..height {
+        let row: String = (0..width).map(|_| char::from(b'0' + rng.below(10) as u8)).collect();
+        writeln!(out, "{}", row).unwrap_or_default();
+    }
+    out
+}
+
+const BRACKETS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+// like the real input every line is either corrupted or incomplete, the first one is always incomplete
+fn brackets(n: usize, rng: &mut Rng) -> String {
+    let mut out = String::new();
+    for i in 0..n {
+        let corrupted = i > 0 && rng.chance(0.5);
+        let len = rng.range(20, 110) as usize;
+        let mut line = String::new();
+        let mut open: Vec<(char, char)> = Vec::new();
+        while line.len() < len || open.is_empty() {
+            // the completion score is 5^depth, real inputs stay well below 20 open brackets
+            if open.is_empty() || (open.len() < 20 && rng.chance(0.55)) {
+                let pair = *rng.pick(&BRACKETS);
+                line.push(pair.0);
+                open.push(pair);
+            } else if let Some((_, close)) = open.pop() {
+                line.push(close);
+            }
+        }
+        if corrupted {
+            let expected = open.last().map(|p| p.1);
+            let wrong = BRACKETS.iter().map(|p| p.1).filter(|c| Some(*c) != expected).collect::<Vec<_>>();
+            line.push(*rng.pick(&wrong));
+        }
+        writeln!(out, "{}", line).unwrap_or_default();
+    }
+    out
+}
+
+// connections between small and big caves, two big caves are never connected
+// because then there would be infinitely many paths. some big caves get a mixed case
+// name like Ab, which is small for the rust version and big for the go one
+fn caves(n: usize, rng: &mut Rng) -> String {
+    let name = |rng: &mut Rng, upper: bool| -> String {
+        let mixed = upper && rng.chance(0.3);
+        (0..2).map(|i| {
+            let c = char::from(b'a' + rng.below(26) as u8);
+            if upper && (i == 0 || !mixed) { c.to_ascii_uppercase() } else { c }
+        }).collect()
+    };
+    let mut small: Vec<String> = vec!["start".to_string(), "end".to_string()];
+    let mut big: Vec<String> = Vec::new();
+    let small_count = (n / 4).clamp(3, 8);
+    let big_count = (n / 10).clamp(1, 3);
+    while small.len() < small_count + 2 {
+        let c = name(rng, false);
+        if !small.contains(&c) {
+            small.push(c);
+        }
+    }
+    while big.len() < big_count {
+        let c = name(rng, true);
+        if !big.contains(&c) {
+            big.push(c);
+        }
+    }
+
+    let caves: Vec<&String> = small.iter().chain(big.iter()).collect();
+    let mut edges: HashSet<(String, String)> = HashSet::new();
+    let mut out = String::new();
+    let mut attempts = 0;
+    while edges.len() < n && attempts < n * 100 {
+        attempts += 1;
+        let (a, b) = (*rng.pick(&caves), *rng.pick(&caves));
+        let both_big = big.contains(a) && big.contains(b);
+        if a == b || both_big || edges.contains(&(b.clone(), a.clone())) || !edges.insert((a.clone(), b.clone())) {
+            continue;
+        }
+        writeln!(out, "{}-{}", a, b).unwrap_or_default();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::DAYS;
+    use aoc_common::InputSource;
+
+    #[test]
+    fn same_seed_same_input() {
+        let opts = GenOptions::default();
+        assert_eq!(generate(8, &opts, &mut Rng::new(3)), generate(8, &opts, &mut Rng::new(3)));
+        assert_ne!(generate(8, &opts, &mut Rng::new(3)), generate(8, &opts, &mut Rng::new(4)));
+    }
+
+    #[test]
+    fn widest_bit_strings_solve() {
+        let opts = GenOptions { size: Some(1000), width: Some(32), height: None };
+        let input = generate(3, &opts, &mut Rng::new(1)).unwrap();
+        let day = crate::days::find_day(3).unwrap();
+        for part in day.run(&input, &InputSource::Stdin, &[1, 2]).parts {
+            assert!(part.answer.is_ok(), "part {}: {:?}", part.part, part.answer);
+        }
+    }
+
+    #[test]
+    fn rejects_unused_and_empty_sizes() {
+        let mut rng = Rng::new(1);
+        assert!(generate(4, &GenOptions { size: Some(0), ..GenOptions::default() }, &mut rng).is_err());
+        assert!(generate(4, &GenOptions { width: Some(8), ..GenOptions::default() }, &mut rng).is_err());
+        assert!(generate(3, &GenOptions { height: Some(8), ..GenOptions::default() }, &mut rng).is_err());
+        assert!(generate(9, &GenOptions { size: Some(8), ..GenOptions::default() }, &mut rng).is_err());
+    }
+
+    #[test]
+    fn generated_inputs_solve() {
+        for seed in 0..5 {
+            for day in DAYS.iter() {
+                let opts = match day.day {
+                    3 => GenOptions { size: Some(12), width: Some(8), height: None },
+                    9 | 11 => GenOptions { size: None, width: Some(8), height: Some(8) },
+                    _ => GenOptions { size: Some(12), width: None, height: None },
+                };
+                let input = generate(day.day, &opts, &mut Rng::new(seed)).unwrap();
+                for part in day.run(&input, &InputSource::Stdin, &[1, 2]).parts {
+                    if day.day == 11 && part.part == 2 {
+                        continue;
+                    }
+                    if let Err(e) = part.answer {
+                        panic!("day {} part {} seed {}: {}\n{}", day.day, part.part, seed, e, input);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt::Write;
+
+pub type Rgb = [u8; 3];
+
+// an rgb image, saved as binary ppm or as pgm after converting to grey
+pub struct Raster {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Raster {
+    // every cell becomes a scale x scale square
+    pub fn from_cells(width: usize, height: usize, scale: usize, colour: impl Fn(i32, i32) -> Rgb) -> Raster {
+        let scale = scale.max(1);
+        let mut pixels = Vec::with_capacity(width * height * scale * scale);
+        for py in 0..height * scale {
+            for px in 0..width * scale {
+                pixels.push(colour((px / scale) as i32, (py / scale) as i32));
+            }
+        }
+        Raster { width: width * scale, height: height * scale, pixels }
+    }
+
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        out.extend(self.pixels.iter().flatten());
+        out
+    }
+
+    pub fn to_pgm(&self) -> Vec<u8> {
+        let mut out = format!("P5\n{} {}\n255\n", self.width, self.height).into_bytes();
+        out.extend(self.pixels.iter().map(|p| luma(*p)));
+        out
+    }
+}
+
+// rec. 601 weights, good enough for a picture of a puzzle
+fn luma([r, g, b]: Rgb) -> u8 {
+    ((r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000) as u8
+}
+
+// blends from black over red and yellow to white, t is clamped to 0..=1
+pub fn heat(t: f64) -> Rgb {
+    let t = t.clamp(0.0, 1.0);
+    let channel = |start: f64| ((t * 3.0 - start).clamp(0.0, 1.0) * 255.0) as u8;
+    [channel(0.0), channel(1.0), channel(2.0)]
+}
+
+// a fixed palette that keeps neighbouring indexes apart
+pub fn palette(i: usize) -> Rgb {
+    const COLOURS: [Rgb; 8] = [
+        [230, 25, 75], [60, 180, 75], [255, 225, 25], [0, 130, 200],
+        [245, 130, 48], [145, 30, 180], [70, 240, 240], [240, 50, 230],
+    ];
+    COLOURS[i % COLOURS.len()]
+}
+
+// collects svg elements, coordinates are written with one decimal
+pub struct Svg {
+    width: f64,
+    height: f64,
+    body: String,
+}
+
+impl Svg {
+    pub fn new(width: f64, height: f64) -> Svg {
+        Svg { width, height, body: String::new() }
+    }
+
+    pub fn line(&mut self, (x1, y1): (f64, f64), (x2, y2): (f64, f64), style: &str) {
+        writeln!(self.body, r#"<line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" style="{}"/>"#, x1, y1, x2, y2, escape(style)).unwrap_or_default();
+    }
+
+    pub fn polyline(&mut self, points: &[(f64, f64)], style: &str) {
+        let points: Vec<String> = points.iter().map(|(x, y)| format!("{:.1},{:.1}", x, y)).collect();
+        writeln!(self.body, r#"<polyline points="{}" style="{}"/>"#, points.join(" "), escape(style)).unwrap_or_default();
+    }
+
+    pub fn circle(&mut self, (x, y): (f64, f64), r: f64, style: &str) {
+        writeln!(self.body, r#"<circle cx="{:.1}" cy="{:.1}" r="{:.1}" style="{}"/>"#, x, y, r, escape(style)).unwrap_or_default();
+    }
+
+    pub fn text(&mut self, (x, y): (f64, f64), text: &str, style: &str) {
+        writeln!(self.body, r#"<text x="{:.1}" y="{:.1}" style="{}">{}</text>"#, x, y, escape(style), escape(text)).unwrap_or_default();
+    }
+
+    pub fn finish(self) -> String {
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n<rect width=\"100%\" height=\"100%\" fill=\"white\"/>\n{}</svg>\n",
+            self.body, w = self.width, h = self.height,
+        )
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_scaled_ppm_and_pgm() {
+        let raster = Raster::from_cells(2, 1, 2, |x, _| if x == 0 { [255, 0, 0] } else { [255, 255, 255] });
+        let ppm = raster.to_ppm();
+        assert!(ppm.starts_with(b"P6\n4 2\n255\n"));
+        assert_eq!(&ppm[11..17], &[255, 0, 0, 255, 0, 0]);
+        assert_eq!(&raster.to_pgm()[11..], &[76, 76, 255, 255, 76, 76, 255, 255]);
+    }
+
+    #[test]
+    fn escapes_svg_text() {
+        let mut svg = Svg::new(10.0, 10.0);
+        svg.text((1.0, 2.0), "a<b & \"c\"", "fill:black");
+        assert!(svg.finish().contains(r#"<text x="1.0" y="2.0" style="fill:black">a&lt;b &amp; &quot;c&quot;</text>"#));
+    }
+}
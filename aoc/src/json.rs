@@ -0,0 +1,64 @@
+use std::fmt::Write;
+
+// just enough json for the runner's records, the crates have no dependencies
+pub enum JsonValue<'a> {
+    Number(u128),
+    Str(&'a str),
+    Null,
+}
+
+// writes a single line object, the fields keep the given order
+pub fn object(fields: &[(&str, JsonValue)]) -> String {
+    let mut out = String::from("{");
+    for (i, (key, value)) in fields.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_str(&mut out, key);
+        out.push(':');
+        match value {
+            JsonValue::Number(n) => write!(out, "{}", n).unwrap_or_default(),
+            JsonValue::Str(s) => write_str(&mut out, s),
+            JsonValue::Null => out.push_str("null"),
+        }
+    }
+    out.push('}');
+    out
+}
+
+fn write_str(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap_or_default(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_fields_in_order() {
+        let o = object(&[
+            ("day", JsonValue::Number(5)),
+            ("answer", JsonValue::Str("5576")),
+            ("error", JsonValue::Null),
+        ]);
+        assert_eq!(o, r#"{"day":5,"answer":"5576","error":null}"#);
+    }
+
+    #[test]
+    fn escapes_strings() {
+        let o = object(&[("error", JsonValue::Str("line 1: 'a\"b'\\\n\u{1}"))]);
+        assert_eq!(o, r#"{"error":"line 1: 'a\"b'\\\n\u0001"}"#);
+    }
+}
@@ -0,0 +1,95 @@
+use std::process::ExitCode;
+
+use cli::{Command, USAGE};
+use days::DAYS;
+
+mod answers;
+mod bench;
+mod check;
+mod cli;
+mod conformance;
+mod config;
+mod days;
+mod export;
+mod gen;
+mod image;
+mod json;
+mod pool;
+mod props;
+mod repl;
+mod run;
+mod verify;
+mod visualize;
+
+fn main() -> ExitCode {
+    let (verbosity, args) = cli::take_verbosity(std::env::args().skip(1));
+    if let Err(e) = aoc_common::log::init(verbosity) {
+        eprintln!("error: {}", e);
+        return ExitCode::from(2);
+    }
+    let command = match cli::parse_args(args.into_iter()) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match command {
+        Command::Help => {
+            println!("{}", USAGE);
+            ExitCode::SUCCESS
+        }
+        Command::List => {
+            for d in DAYS.iter() {
+                println!("day {:02}: {}", d.day, d.title);
+                for (name, value) in d.options(&[]).unwrap_or_default() {
+                    println!("    {} = {}", name, value);
+                }
+            }
+            ExitCode::SUCCESS
+        }
+        Command::Run(args) => run::run(&args),
+        Command::Verify(args) => verify::verify(&args),
+        Command::Bench(args) => bench::bench(&args),
+        Command::Conformance(args) => conformance::conformance(&args),
+        Command::Export(args) => match days::find_day(args.day) {
+            Some(day) => export::export(day, &args),
+            None => {
+                eprintln!("error: day {} is not solved", args.day);
+                ExitCode::from(2)
+            }
+        },
+        Command::Check(args) => check::check(&args),
+        Command::Repl(args) => match days::find_day(args.day) {
+            Some(day) => repl::repl(day, &args),
+            None => {
+                eprintln!("error: day {} is not solved", args.day);
+                ExitCode::from(2)
+            }
+        },
+        Command::Prop(args) => props::run_properties(&args),
+        Command::Gen(args) => {
+            let input = match gen::generate(args.day, &args.options, &mut aoc_common::Rng::new(args.seed)) {
+                Ok(i) => i,
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    return ExitCode::from(2);
+                }
+            };
+            match &args.output {
+                Some(path) => match std::fs::write(path, input) {
+                    Ok(()) => ExitCode::SUCCESS,
+                    Err(e) => {
+                        eprintln!("error: failed to write {}: {}", path.display(), e);
+                        ExitCode::FAILURE
+                    }
+                },
+                None => {
+                    print!("{}", input);
+                    ExitCode::SUCCESS
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use aoc_common::panic_message;
+
+// runs f on every item using `jobs` worker threads, results come back in the order of the items.
+// a panic in f only fails that item, the message ends up in its Err
+pub fn map_parallel<T, R, F>(items: &[T], jobs: usize, f: F) -> Vec<Result<R, String>>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<Result<R, String>>>> = Mutex::new((0..items.len()).map(|_| None).collect());
+
+    thread::scope(|s| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            s.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::SeqCst);
+                if i >= items.len() {
+                    break;
+                }
+                let result = panic::catch_unwind(AssertUnwindSafe(|| f(&items[i]))).map_err(|p| panic_message(&*p));
+                results.lock().unwrap_or_else(|e| e.into_inner())[i] = Some(result);
+            });
+        }
+    });
+
+    results.into_inner().unwrap_or_else(|e| e.into_inner()).into_iter()
+        .map(|r| r.expect("every item is picked up by a worker"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_order_of_items() {
+        let items: Vec<u64> = (0..50).collect();
+        let squares = map_parallel(&items, 4, |i| i * i);
+        assert_eq!(squares, items.iter().map(|i| Ok(i * i)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn a_panic_fails_only_its_item() {
+        let prev = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let results = map_parallel(&[1, 0, 2], 2, |i| 10 / i);
+        panic::set_hook(prev);
+
+        assert_eq!(results[0], Ok(10));
+        assert!(results[1].as_ref().unwrap_err().starts_with("panicked"));
+        assert_eq!(results[2], Ok(5));
+    }
+}
@@ -0,0 +1,167 @@
+use std::process::ExitCode;
+
+use aoc_common::prop::{self, shrink_number, shrink_vec, Config};
+use aoc_common::Rng;
+use day06::{Day06Options, FishPopulation};
+
+use crate::cli::PropArgs;
+use crate::gen::{generate, GenOptions};
+
+// an invariant of one day's solver, checked on inputs from the generators
+pub struct Property {
+    pub day: u32,
+    pub name: &'static str,
+    // Ok(number of cases) or the report of the minimal counterexample
+    pub check: fn(&Config) -> Result<u32, String>,
+}
+
+pub const PROPERTIES: [Property; 4] = [
+    Property { day: 6, name: "aging keeps every fish that isn't a newborn", check: day06_age_conserves_fish },
+    Property { day: 7, name: "median optimum equals the brute force minimum for part 1", check: day07_part1_optimum },
+    Property { day: 7, name: "mean optimum equals the brute force minimum for part 2", check: day07_part2_optimum },
+    Property { day: 12, name: "part 2 finds at least as many paths as part 1", check: day12_part2_has_more_paths },
+];
+
+pub fn run_properties(args: &PropArgs) -> ExitCode {
+    let config = Config { cases: args.cases, seed: args.seed, ..Config::default() };
+    // failing cases panic over and over while shrinking, the report below is enough
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+
+    let mut failed = 0;
+    for p in PROPERTIES.iter().filter(|p| args.day.is_none_or(|d| d == p.day)) {
+        match (p.check)(&config) {
+            Ok(cases) => println!("ok      day {:02}: {} ({} cases)", p.day, p.name, cases),
+            Err(report) => {
+                println!("FAILED  day {:02}: {}\n{}", p.day, p.name, report);
+                failed += 1;
+            }
+        }
+    }
+
+    std::panic::set_hook(hook);
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+// small generated inputs keep the brute force solvers quick and the counterexamples readable
+fn generated(day: u32, size: usize, rng: &mut Rng) -> String {
+    let opts = GenOptions { size: Some(size), ..GenOptions::default() };
+    generate(day, &opts, rng).expect("every day used here has a generator")
+}
+
+fn day06_age_conserves_fish(config: &Config) -> Result<u32, String> {
+    let generate = |rng: &mut Rng| {
+        let size = rng.range(1, 20) as usize;
+        let timers = day06::parse_input(&generated(6, size, rng), &Day06Options::default()).expect("generated input parses");
+        (timers, rng.below(100))
+    };
+    let shrink = |(timers, days): &(Vec<u64>, u64)| {
+        let mut candidates: Vec<(Vec<u64>, u64)> = shrink_vec(timers, |t| shrink_number(*t)).into_iter().map(|t| (t, *days)).collect();
+        candidates.extend(shrink_number(*days).into_iter().map(|d| (timers.clone(), d)));
+        candidates
+    };
+    let property = |(timers, days): &(Vec<u64>, u64)| {
+        let mut fishes = FishPopulation::new();
+        timers.iter().for_each(|t| fishes.add_fish(*t));
+        for _ in 0..*days {
+            fishes.age();
+        }
+        let before = fishes.total_number();
+        fishes.age();
+        let newborn = fishes.fishes_with_timers[fishes.fishes_with_timers.len() - 1];
+        if fishes.total_number() - newborn != before {
+            return Err(format!("{} fish before aging, {} after without the {} newborns", before, fishes.total_number() - newborn, newborn));
+        }
+        Ok(())
+    };
+    prop::check(config, generate, shrink, property).map_err(|f| f.to_string())
+}
+
+fn crabs(rng: &mut Rng) -> Vec<u32> {
+    let size = rng.range(1, 30) as usize;
+    day07::parse_input(&generated(7, size, rng)).expect("generated input parses")
+}
+
+fn shrink_crabs(crabs: &[u32]) -> Vec<Vec<u32>> {
+    shrink_vec(crabs, |c| shrink_number(*c as u64).into_iter().map(|c| c as u32).collect())
+        .into_iter()
+        .filter(|c| !c.is_empty())
+        .collect()
+}
+
+fn optimum_matches(crabs: &[u32], fast: fn(&[u32]) -> Option<i64>, cost: fn(&[u32], u32) -> i64) -> Result<(), String> {
+    let brute_force = day07::costs(crabs, cost).into_iter().min();
+    let closed_form = fast(crabs);
+    if brute_force != closed_form {
+        return Err(format!("brute force minimum {:?}, closed form {:?}", brute_force, closed_form));
+    }
+    Ok(())
+}
+
+fn day07_part1_optimum(config: &Config) -> Result<u32, String> {
+    prop::check(config, crabs, |c: &Vec<u32>| shrink_crabs(c), |c| optimum_matches(c, day07::optimal_cost_part_1, day07::cost_of_alignment_part_1))
+        .map_err(|f| f.to_string())
+}
+
+fn day07_part2_optimum(config: &Config) -> Result<u32, String> {
+    prop::check(config, crabs, |c: &Vec<u32>| shrink_crabs(c), |c| optimum_matches(c, day07::optimal_cost_part_2, day07::cost_of_alignment_part_2))
+        .map_err(|f| f.to_string())
+}
+
+fn day12_part2_has_more_paths(config: &Config) -> Result<u32, String> {
+    let generate = |rng: &mut Rng| {
+        let size = rng.range(1, 12) as usize;
+        generated(12, size, rng).lines().map(|l| l.to_string()).collect::<Vec<String>>()
+    };
+    let shrink = |lines: &Vec<String>| shrink_caves(lines);
+    let property = |lines: &Vec<String>| {
+        let caves = day12::parse_input_lines_into_caves(lines).map_err(|e| e.to_string())?;
+        let part1 = day12::find_paths_part_1(&caves).len();
+        let part2 = day12::find_paths_part_2(&caves).len();
+        if part2 < part1 {
+            return Err(format!("part 1 finds {} paths, part 2 only {}", part1, part2));
+        }
+        Ok(())
+    };
+    prop::check(config, generate, shrink, property).map_err(|f| f.to_string())
+}
+
+// dropping lines can lose the start or end cave, such a candidate fails for another reason
+// than the original and would pull the shrinking away from the real counterexample
+fn shrink_caves(lines: &[String]) -> Vec<Vec<String>> {
+    shrink_vec(lines, |_| Vec::new()).into_iter().filter(|candidate| has_start_and_end(candidate)).collect()
+}
+
+fn has_start_and_end(lines: &[String]) -> bool {
+    day12::parse_input_lines_into_caves(lines).is_ok_and(|caves| {
+        let names = caves.caves();
+        names.contains(&"start") && names.contains(&"end")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_properties_hold() {
+        let config = Config { cases: 30, ..Config::default() };
+        for p in PROPERTIES.iter() {
+            if let Err(report) = (p.check)(&config) {
+                panic!("day {}: {}\n{}", p.day, p.name, report);
+            }
+        }
+    }
+
+    #[test]
+    fn cave_shrinks_keep_start_and_end() {
+        let lines: Vec<String> = ["start-A", "A-b", "A-end", "b-end"].iter().map(|l| l.to_string()).collect();
+        let candidates = shrink_caves(&lines);
+        assert!(!candidates.is_empty());
+        assert!(candidates.iter().all(|c| has_start_and_end(c)));
+    }
+}
@@ -0,0 +1,339 @@
+use std::io::{BufRead, IsTerminal, Write};
+use std::process::ExitCode;
+
+use day04::BingoBoard;
+use day06::FishPopulation;
+
+use crate::cli::ReplArgs;
+use crate::days::Day;
+
+const HELP: &str = "commands:
+    step [k]     advance k steps (1 by default)
+    goto <step>  jump to a step, going back replays from the start
+    show         print the current state
+    stats        print the counters of the current state
+    reset        go back to step 0
+    help         print this
+    quit         leave, so does end of input";
+
+// one of the stateful puzzles, built from the input and moved forward one step at a time
+trait Simulation {
+    // returns false when there is nothing left to step through
+    fn step(&mut self) -> bool;
+    fn steps(&self) -> u32;
+    fn show(&self) -> String;
+    fn stats(&self) -> String;
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum ReplCommand {
+    Step(u32),
+    Goto(u32),
+    Show,
+    Stats,
+    Reset,
+    Help,
+    Quit,
+}
+
+// None for an empty line
+fn parse_command(line: &str) -> Result<Option<ReplCommand>, String> {
+    let mut words = line.split_whitespace();
+    let command = match words.next() {
+        None => return Ok(None),
+        Some(c) => c,
+    };
+    let number = |value: Option<&str>| -> Result<Option<u32>, String> {
+        value.map(|v| v.parse().map_err(|_| format!("expected a number, got '{}'", v))).transpose()
+    };
+
+    let parsed = match command {
+        "step" | "s" => ReplCommand::Step(number(words.next())?.unwrap_or(1)),
+        "goto" | "g" => ReplCommand::Goto(number(words.next())?.ok_or("goto needs the step to go to")?),
+        "show" => ReplCommand::Show,
+        "stats" => ReplCommand::Stats,
+        "reset" => ReplCommand::Reset,
+        "help" | "?" => ReplCommand::Help,
+        "quit" | "exit" | "q" => ReplCommand::Quit,
+        _ => return Err(format!("unknown command '{}', try help", command)),
+    };
+    if let Some(extra) = words.next() {
+        return Err(format!("unexpected '{}' after {}", extra, command));
+    }
+    Ok(Some(parsed))
+}
+
+pub fn repl(day: &Day, args: &ReplArgs) -> ExitCode {
+    let source = args.input.clone().unwrap_or_else(|| day.default_input());
+    let input = match source.read() {
+        Ok(i) => i,
+        Err(e) => {
+            eprintln!("error: failed to read {}: {}", source, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let start: fn(&str) -> Result<Box<dyn Simulation>, String> = match day.day {
+        4 => Bingo::start,
+        6 => Lanternfish::start,
+        11 => Octopuses::start,
+        _ => {
+            eprintln!("error: day {} has no simulation to step through, only days 4, 6 and 11 have one", day.day);
+            return ExitCode::from(2);
+        }
+    };
+    let mut simulation = match start(&input) {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let stdin = std::io::stdin();
+    // no prompt when the commands are piped in
+    let interactive = stdin.is_terminal();
+    if interactive {
+        println!("day {:02}: {}, type help for the commands", day.day, day.title);
+    }
+    let mut lines = stdin.lock().lines();
+    loop {
+        if interactive {
+            print!("> ");
+            let _ = std::io::stdout().flush();
+        }
+        let line = match lines.next() {
+            Some(Ok(l)) => l,
+            Some(Err(e)) => {
+                eprintln!("error: failed to read a command: {}", e);
+                return ExitCode::FAILURE;
+            }
+            None => return ExitCode::SUCCESS,
+        };
+
+        let command = match parse_command(&line) {
+            Ok(Some(c)) => c,
+            Ok(None) => continue,
+            Err(e) => {
+                println!("error: {}", e);
+                continue;
+            }
+        };
+        match command {
+            ReplCommand::Step(k) => {
+                advance(simulation.as_mut(), k);
+                println!("{}", simulation.stats());
+            }
+            ReplCommand::Goto(target) => {
+                if target < simulation.steps() {
+                    simulation = start(&input).expect("the input parsed before");
+                }
+                let steps = target - simulation.steps();
+                advance(simulation.as_mut(), steps);
+                println!("{}", simulation.stats());
+            }
+            ReplCommand::Show => print!("{}", simulation.show()),
+            ReplCommand::Stats => println!("{}", simulation.stats()),
+            ReplCommand::Reset => {
+                simulation = start(&input).expect("the input parsed before");
+                println!("{}", simulation.stats());
+            }
+            ReplCommand::Help => println!("{}", HELP),
+            ReplCommand::Quit => return ExitCode::SUCCESS,
+        }
+    }
+}
+
+fn advance(simulation: &mut dyn Simulation, steps: u32) {
+    for _ in 0..steps {
+        if !simulation.step() {
+            println!("the simulation ended after step {}", simulation.steps());
+            break;
+        }
+    }
+}
+
+// a step draws the next number, boards that won stop playing like in find_winning_boards
+struct Bingo {
+    drawn_numbers: Vec<u32>,
+    boards: Vec<BingoBoard>,
+    // for every board the draw it won on and its score
+    won: Vec<Option<(u32, u32)>>,
+    draws: u32,
+}
+
+impl Bingo {
+    fn start(input: &str) -> Result<Box<dyn Simulation>, String> {
+        let bingo = day04::parse_input(input, day04::Day04Options::default().board_size).map_err(|e| e.to_string())?;
+        let won = vec![None; bingo.boards.len()];
+        Ok(Box::new(Bingo { drawn_numbers: bingo.drawn_numbers, boards: bingo.boards, won, draws: 0 }))
+    }
+}
+
+impl Simulation for Bingo {
+    fn step(&mut self) -> bool {
+        let n = match self.drawn_numbers.get(self.draws as usize) {
+            Some(n) => *n,
+            None => return false,
+        };
+        self.draws += 1;
+        for (board, won) in self.boards.iter_mut().zip(self.won.iter_mut()) {
+            if won.is_none() && board.draw_number(n).is_some() {
+                *won = Some((self.draws, board.sum_of_unmarked_numbers() * n));
+            }
+        }
+        true
+    }
+
+    fn steps(&self) -> u32 {
+        self.draws
+    }
+
+    fn show(&self) -> String {
+        let mut out = String::new();
+        for (i, (board, won)) in self.boards.iter().zip(&self.won).enumerate() {
+            match won {
+                Some((draw, score)) => out += &format!("board {}, won on draw {} with score {}\n", i + 1, draw, score),
+                None => out += &format!("board {}\n", i + 1),
+            }
+            out += &format!("{}\n", board);
+        }
+        out
+    }
+
+    fn stats(&self) -> String {
+        let last = match self.draws {
+            0 => "nothing drawn yet".to_string(),
+            d => format!("last number {}", self.drawn_numbers[d as usize - 1]),
+        };
+        let mut winners: Vec<(usize, u32, u32)> = self.won.iter().enumerate().filter_map(|(i, w)| w.map(|(d, s)| (i + 1, d, s))).collect();
+        winners.sort_by_key(|(_, draw, _)| *draw);
+        let mut stats = format!("draw {}/{}, {}, {} of {} boards won", self.draws, self.drawn_numbers.len(), last, winners.len(), self.boards.len());
+        if let Some((board, draw, score)) = winners.first() {
+            stats += &format!("\nfirst winner: board {} on draw {}, score {}", board, draw, score);
+        }
+        if winners.len() > 1 {
+            let (board, draw, score) = winners[winners.len() - 1];
+            stats += &format!("\nlatest winner: board {} on draw {}, score {}", board, draw, score);
+        }
+        stats
+    }
+}
+
+// a step is a day
+struct Lanternfish {
+    fishes: FishPopulation,
+    days: u32,
+}
+
+impl Lanternfish {
+    fn start(input: &str) -> Result<Box<dyn Simulation>, String> {
+        let timers = day06::parse_input(input, &day06::Day06Options::default()).map_err(|e| e.to_string())?;
+        let mut fishes = FishPopulation::new();
+        timers.iter().for_each(|t| fishes.add_fish(*t));
+        Ok(Box::new(Lanternfish { fishes, days: 0 }))
+    }
+}
+
+impl Simulation for Lanternfish {
+    fn step(&mut self) -> bool {
+        // stop well before the counts, or their total, outgrow u64
+        if self.fishes.fishes_with_timers.iter().any(|n| *n > u64::MAX / 32) {
+            return false;
+        }
+        self.fishes.age();
+        self.days += 1;
+        true
+    }
+
+    fn steps(&self) -> u32 {
+        self.days
+    }
+
+    fn show(&self) -> String {
+        let mut out = String::new();
+        for (timer, n) in self.fishes.fishes_with_timers.iter().enumerate() {
+            out += &format!("timer {}: {}\n", timer, n);
+        }
+        out
+    }
+
+    fn stats(&self) -> String {
+        format!("day {}, {} fish, {} about to spawn", self.days, self.fishes.total_number(), self.fishes.fishes_with_timers[0])
+    }
+}
+
+// a step is a day11 step, the steps where every octopus flashed are remembered
+struct Octopuses {
+    map: day11::Map,
+    last_step_flashes: usize,
+    synchronised: Vec<u32>,
+}
+
+impl Octopuses {
+    fn start(input: &str) -> Result<Box<dyn Simulation>, String> {
+        let energies = day11::parse_input(input).map_err(|e| e.to_string())?;
+        Ok(Box::new(Octopuses { map: day11::Map::new(&energies), last_step_flashes: 0, synchronised: Vec::new() }))
+    }
+}
+
+impl Simulation for Octopuses {
+    fn step(&mut self) -> bool {
+        let before = self.map.total_flashes_number;
+        if self.map.step() {
+            self.synchronised.push(self.map.step);
+        }
+        self.last_step_flashes = (self.map.total_flashes_number - before) as usize;
+        true
+    }
+
+    fn steps(&self) -> u32 {
+        self.map.step
+    }
+
+    fn show(&self) -> String {
+        self.map.to_string()
+    }
+
+    fn stats(&self) -> String {
+        let mut stats = format!("step {}, {} flashes in the last step, {} in total", self.map.step, self.last_step_flashes, self.map.total_flashes_number);
+        if let Some(first) = self.synchronised.first() {
+            stats += &format!(", all flashed together first in step {}", first);
+        }
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_commands() {
+        assert_eq!(parse_command("  "), Ok(None));
+        assert_eq!(parse_command("step"), Ok(Some(ReplCommand::Step(1))));
+        assert_eq!(parse_command("step 10"), Ok(Some(ReplCommand::Step(10))));
+        assert_eq!(parse_command("goto 3"), Ok(Some(ReplCommand::Goto(3))));
+        assert!(parse_command("goto").is_err());
+        assert!(parse_command("step x").is_err());
+        assert!(parse_command("show 1").is_err());
+        assert!(parse_command("jump").is_err());
+    }
+
+    #[test]
+    fn steps_through_the_examples() {
+        let mut octopuses = Octopuses::start(include_str!("../../day11/example")).unwrap();
+        advance(octopuses.as_mut(), 100);
+        assert!(octopuses.stats().ends_with("1656 in total"));
+
+        let mut fish = Lanternfish::start(include_str!("../../day06/example")).unwrap();
+        advance(fish.as_mut(), 18);
+        assert!(fish.stats().starts_with("day 18, 26 fish"));
+
+        let mut bingo = Bingo::start(include_str!("../../day04/example")).unwrap();
+        advance(bingo.as_mut(), 12);
+        assert!(bingo.stats().contains("first winner: board 3 on draw 12, score 4512"));
+        advance(bingo.as_mut(), 100);
+        assert_eq!(bingo.steps(), 27);
+        assert!(bingo.stats().contains("latest winner: board 2 on draw 15, score 1924"));
+    }
+}
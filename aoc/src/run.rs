@@ -0,0 +1,223 @@
+use std::io::Write;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use aoc_common::{load_input, DayRun, ParseError, PartRun, Solution};
+
+use crate::bench::format_duration;
+use crate::cli::{Format, RunArgs};
+use crate::config::{parse_config, settings_for};
+use crate::days::{find_day, options_from, Day, Settings, DAYS};
+use crate::json::{object, JsonValue};
+use crate::pool::map_parallel;
+
+pub fn run(args: &RunArgs) -> ExitCode {
+    let days: Vec<&Day> = if args.all {
+        DAYS.iter().collect()
+    } else {
+        let day = args.day.unwrap_or_default();
+        match find_day(day) {
+            Some(d) => vec![d],
+            None => {
+                eprintln!("error: day {} is not solved", day);
+                return ExitCode::from(2);
+            }
+        }
+    };
+    if args.visualize {
+        return crate::visualize::visualize(days[0], args);
+    }
+    if args.report {
+        return report(days[0], args);
+    }
+    let parts = match args.part {
+        Some(p) => vec![p],
+        None => vec![1, 2],
+    };
+    if args.stream && !args.all && !days[0].can_stream(&parts) {
+        match days[0].stream {
+            None => eprintln!("error: day {} can't be streamed, only days 1, 2, 3 and 10 can", days[0].day),
+            Some(_) => {
+                let streamed: Vec<String> = days[0].streamed_parts.iter().map(|p| p.to_string()).collect();
+                eprintln!("error: day {} can only stream part {}, pick it with --part", days[0].day, streamed.join(" and "));
+            }
+        }
+        return ExitCode::from(2);
+    }
+
+    // the options are checked up front, a typo shouldn't show up as twelve failed days
+    let mut settings = match &args.config {
+        Some(path) => match load_input(path).map_err(|e| e.to_string()).and_then(|t| parse_config(&t).map_err(|e| e.with_file(path.display()).to_string())) {
+            Ok(s) => s,
+            Err(e) => {
+                eprintln!("error: failed to load config {}: {}", path.display(), e);
+                return ExitCode::from(2);
+            }
+        },
+        None => Vec::new(),
+    };
+    settings.extend(args.settings.iter().cloned());
+    let day_settings: Vec<Vec<(String, String)>> = days.iter().map(|d| settings_for(d.day, &settings)).collect();
+    for (day, s) in days.iter().zip(&day_settings) {
+        if let Err(e) = day.options(s) {
+            eprintln!("error: day {:02}: {}", day.day, e);
+            return ExitCode::from(2);
+        }
+    }
+    if args.monitor {
+        return monitor(days[0], args, &day_settings[0]);
+    }
+
+    let start = Instant::now();
+    let jobs = args.jobs.unwrap_or(1) as usize;
+    let work: Vec<(&Day, &Vec<(String, String)>)> = days.iter().copied().zip(&day_settings).collect();
+    let results = map_parallel(&work, jobs, |(day, settings)| run_day(day, args, &parts, settings));
+    let total = start.elapsed();
+
+    // a panicking solver fails all the parts of its day
+    let results: Vec<Vec<PartRun>> = results.into_iter()
+        .map(|r| r.unwrap_or_else(|e| DayRun::failed(&parts, &e).parts))
+        .collect();
+
+    for (day, day_results) in days.iter().zip(&results) {
+        for r in day_results {
+            match args.format {
+                Format::Json => println!("{}", part_record(day.day, r, &r.answer)),
+                Format::Text if !args.all => print_part(day, r),
+                Format::Text => {}
+            }
+        }
+    }
+    if args.all && args.format == Format::Text {
+        print_summary(&days, &results, total);
+    }
+
+    if results.iter().flatten().any(|r| r.answer.is_err()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_day(day: &Day, args: &RunArgs, parts: &[u32], settings: &Settings) -> Vec<PartRun> {
+    let source = match &args.input {
+        Some(i) => i.clone(),
+        None => day.default_input(),
+    };
+    // with --all the days that can't stream fall back to reading the whole input
+    if let (true, Some(stream)) = (args.stream && day.can_stream(parts), day.stream) {
+        return stream(&source, parts, settings).parts;
+    }
+    match source.read() {
+        Ok(input) => day.run_with(&input, &source, parts, settings).parts,
+        Err(e) => DayRun::failed(parts, &format!("failed to read {}: {}", source, e)).parts,
+    }
+}
+
+// only day01 has a report so far
+fn report(day: &Day, args: &RunArgs) -> ExitCode {
+    if day.day != 1 {
+        eprintln!("error: day {} has no report, only day 1 has one", day.day);
+        return ExitCode::from(2);
+    }
+    let source = args.input.clone().unwrap_or_else(|| day.default_input());
+    let input = match source.read() {
+        Ok(i) => i,
+        Err(e) => {
+            eprintln!("error: failed to read {}: {}", source, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    match day01::Day01::parse(&input) {
+        Ok(depths) => {
+            print!("{}", day01::depth_report(&depths));
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {}", e.with_file(&source));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// prints the counters after every depth, so a feed on stdin is reported as it comes in
+fn monitor(day: &Day, args: &RunArgs, settings: &Settings) -> ExitCode {
+    if day.day != 1 {
+        eprintln!("error: day {} has no monitor, only day 1 has one", day.day);
+        return ExitCode::from(2);
+    }
+    let source = args.input.clone().unwrap_or_else(|| day.default_input());
+    let reader = match source.reader() {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("error: failed to read {}: {}", source, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    // the settings were validated already
+    let options = options_from::<day01::Day01>(settings).unwrap_or_default();
+    let mut monitor = day01::DepthMonitor::new(options.window);
+    let mut out = std::io::stdout().lock();
+    match monitor.watch(reader, |m| {
+        let _ = writeln!(out, "{}", m);
+        let _ = out.flush();
+    }) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            match e.downcast::<ParseError>() {
+                Ok(e) => eprintln!("error: {}", e.with_file(&source)),
+                Err(e) if e.is::<std::io::Error>() => eprintln!("error: failed to read {}: {}", source, e),
+                Err(e) => eprintln!("error: {}", e),
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn print_part(day: &Day, r: &PartRun) {
+    match &r.answer {
+        Ok(answer) => println!("day {:02} part {}: {}", day.day, r.part, answer),
+        Err(e) => eprintln!("day {:02} part {}: error: {}", day.day, r.part, e),
+    }
+}
+
+// one row per part, the errors are listed below the table so that it stays readable
+fn print_summary(days: &[&Day], results: &[Vec<PartRun>], total: Duration) {
+    println!("{:<4} {:<4} {:<16} {:>10}  status", "day", "part", "answer", "time");
+    let mut errors = Vec::new();
+    for (day, day_results) in days.iter().zip(results) {
+        for r in day_results {
+            let (answer, status) = match &r.answer {
+                Ok(a) => (a.as_str(), "ok"),
+                Err(e) => {
+                    errors.push(format!("day {:02} part {}: {}", day.day, r.part, e));
+                    ("-", "FAILED")
+                }
+            };
+            println!("{:<4} {:<4} {:<16} {:>10}  {}", format!("{:02}", day.day), r.part, answer, format_duration(r.elapsed), status);
+        }
+    }
+
+    let parts = results.iter().map(|r| r.len()).sum::<usize>();
+    println!("{} days, {} parts, {} failed in {}", days.len(), parts, errors.len(), format_duration(total));
+    for e in errors {
+        eprintln!("error: {}", e);
+    }
+}
+
+// {"day":5,"part":1,"answer":"5576","elapsed_ns":123}, answers are strings because some don't fit a double
+fn part_record(day: u32, r: &PartRun, answer: &Result<String, String>) -> String {
+    let mut fields = vec![
+        ("day", JsonValue::Number(day as u128)),
+        ("part", JsonValue::Number(r.part as u128)),
+    ];
+    match answer {
+        Ok(a) => fields.push(("answer", JsonValue::Str(a))),
+        Err(e) => {
+            fields.push(("answer", JsonValue::Null));
+            fields.push(("error", JsonValue::Str(e)));
+        }
+    }
+    fields.push(("elapsed_ns", JsonValue::Number(r.elapsed.as_nanos())));
+    object(&fields)
+}
@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aoc_common::{load_input, InputSource};
+
+use crate::answers::{parse_answers, Answer};
+use crate::cli::VerifyArgs;
+use crate::days::{find_day, DAYS};
+
+#[derive(Default)]
+struct Summary {
+    passed: u32,
+    failed: u32,
+    missing: u32,
+}
+
+// runs every solver that has a stored answer and compares the results,
+// every day's own input without a stored answer is reported as missing
+pub fn verify(args: &VerifyArgs) -> ExitCode {
+    let answers = match load_input(&args.answers) {
+        Ok(text) => match parse_answers(&text) {
+            Ok(a) => a,
+            Err(e) => {
+                eprintln!("error: {}", e.with_file(args.answers.display()));
+                return ExitCode::from(2);
+            }
+        },
+        Err(e) => {
+            eprintln!("error: failed to read {}: {}", args.answers.display(), e);
+            return ExitCode::from(2);
+        }
+    };
+    let answers: Vec<&Answer> = answers.iter().filter(|a| args.day.is_none_or(|d| d == a.day)).collect();
+
+    let mut summary = Summary::default();
+    // the same input is usually checked for both parts, read it only once
+    let mut inputs: HashMap<PathBuf, Result<String, String>> = HashMap::new();
+
+    for answer in &answers {
+        let path = answer.input_path(&args.answers);
+        let label = format!("day {:02} part {} ({})", answer.day, answer.part, path.display());
+        let day = match find_day(answer.day) {
+            Some(d) => d,
+            None => {
+                println!("FAIL    {}: day {} is not solved", label, answer.day);
+                summary.failed += 1;
+                continue;
+            }
+        };
+
+        let input = inputs.entry(path.clone())
+            .or_insert_with(|| load_input(&path).map_err(|e| format!("failed to read input: {}", e)));
+        let result = match input {
+            Ok(text) => day.solve(answer.part, text, &InputSource::File(path.clone())),
+            Err(e) => Err(e.clone()),
+        };
+
+        match result {
+            Ok(got) if got == answer.value => {
+                println!("PASS    {}: {}", label, got);
+                summary.passed += 1;
+            }
+            Ok(got) => {
+                println!("FAIL    {}: expected {}, got {}", label, answer.value, got);
+                summary.failed += 1;
+            }
+            Err(e) => {
+                println!("FAIL    {}: {}", label, e);
+                summary.failed += 1;
+            }
+        }
+    }
+
+    for day in DAYS.iter().filter(|d| args.day.is_none_or(|n| n == d.day)) {
+        for part in [1, 2] {
+            let recorded = answers.iter().any(|a| a.day == day.day && a.part == part && a.input == aoc_common::DEFAULT_INPUT);
+            if !recorded {
+                println!("MISSING day {:02} part {}: no answer stored for day{:02}/{}", day.day, part, day.day, aoc_common::DEFAULT_INPUT);
+                summary.missing += 1;
+            }
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing", summary.passed, summary.failed, summary.missing);
+
+    if summary.failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,245 @@
+use std::fmt::Write as _;
+use std::io::Write;
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+use aoc_common::{Grid, Solution};
+
+use crate::cli::RunArgs;
+use crate::days::Day;
+
+pub const DEFAULT_DELAY_MS: u64 = 50;
+// big enough for the day09 and day11 maps, day05 gets cropped to its top left corner
+const DEFAULT_VIEWPORT_SIDE: (usize, usize) = (100, 100);
+
+const CLEAR: &str = "\x1b[H\x1b[2J";
+const RESET: &str = "\x1b[0m";
+
+// the part of the map that gets drawn, in map coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Viewport {
+    pub x: i32,
+    pub y: i32,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Viewport {
+    // x,y,width,height
+    pub fn parse(value: &str) -> Result<Viewport, String> {
+        let numbers: Vec<&str> = value.split(',').map(|n| n.trim()).collect();
+        let err = || format!("expected the viewport as x,y,width,height, got '{}'", value);
+        if numbers.len() != 4 {
+            return Err(err());
+        }
+        let x = numbers[0].parse().map_err(|_| err())?;
+        let y = numbers[1].parse().map_err(|_| err())?;
+        let width = numbers[2].parse().map_err(|_| err())?;
+        let height = numbers[3].parse().map_err(|_| err())?;
+        if width == 0 || height == 0 {
+            return Err(err());
+        }
+        Ok(Viewport { x, y, width, height })
+    }
+
+    fn fitting<T>(grid: &Grid<T>) -> Viewport {
+        Viewport { x: 0, y: 0, width: grid.width().min(DEFAULT_VIEWPORT_SIDE.0), height: grid.height().min(DEFAULT_VIEWPORT_SIDE.1) }
+    }
+
+    fn coords(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        (self.y..self.y + self.height as i32).flat_map(move |y| (self.x..self.x + self.width as i32).map(move |x| (x, y)))
+    }
+}
+
+// one character on screen with its ansi style, e.g. "1;33" for bold yellow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub ch: char,
+    pub style: &'static str,
+}
+
+// draws the viewport, cells outside of the map stay blank. styles are only emitted when they change
+pub fn render(viewport: &Viewport, cell: impl Fn(i32, i32) -> Option<Cell>) -> String {
+    let mut out = String::new();
+    for y in viewport.y..viewport.y + viewport.height as i32 {
+        let mut style = "";
+        for x in viewport.x..viewport.x + viewport.width as i32 {
+            let c = cell(x, y).unwrap_or(Cell { ch: ' ', style: "" });
+            if c.style != style {
+                out.push_str(RESET);
+                if !c.style.is_empty() {
+                    write!(out, "\x1b[{}m", c.style).unwrap_or_default();
+                }
+                style = c.style;
+            }
+            out.push(c.ch);
+        }
+        if !style.is_empty() {
+            out.push_str(RESET);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+struct Screen {
+    delay: Duration,
+}
+
+impl Screen {
+    fn show(&self, frame: &str, status: &str) {
+        let mut stdout = std::io::stdout().lock();
+        let _ = writeln!(stdout, "{}{}{}", CLEAR, frame, status);
+        let _ = stdout.flush();
+        thread::sleep(self.delay);
+    }
+}
+
+pub fn visualize(day: &Day, args: &RunArgs) -> ExitCode {
+    let source = args.input.clone().unwrap_or_else(|| day.default_input());
+    let input = match source.read() {
+        Ok(i) => i,
+        Err(e) => {
+            eprintln!("error: failed to read {}: {}", source, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let screen = Screen { delay: Duration::from_millis(args.delay.unwrap_or(DEFAULT_DELAY_MS)) };
+
+    let result = match day.day {
+        5 => day05_lines(&input, args, &screen),
+        9 => day09_basins(&input, args, &screen),
+        11 => day11_octopuses(&input, args, &screen),
+        _ => Err(format!("day {} has no visualisation, only days 5, 9 and 11 have one", day.day)),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e.replace('\n', " "));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// every line is drawn on its own frame, the points it just covered are highlighted
+fn day05_lines(input: &str, args: &RunArgs, screen: &Screen) -> Result<(), String> {
+    let lines = day05::Day05::parse(input).map_err(|e| e.to_string())?;
+    let lines: Vec<&day05::Line> = lines.iter().filter(|l| args.part != Some(1) || l.is_horizontal() || l.is_vertical()).collect();
+    let mut map = day05::Map::new(day05::MAP_SIDE);
+    let viewport = args.viewport.unwrap_or_else(|| Viewport::fitting(map.field()));
+
+    let snapshot = |map: &day05::Map| viewport.coords().map(|(x, y)| map.field().get(x, y).copied().unwrap_or(0)).collect::<Vec<_>>();
+    for (i, line) in lines.iter().enumerate() {
+        let before = snapshot(&map);
+        map.mark_line(line);
+        let after = snapshot(&map);
+        // lines that miss the viewport don't change the picture
+        if before == after && i + 1 != lines.len() {
+            continue;
+        }
+
+        let frame = render(&viewport, |x, y| {
+            let n = *map.field().get(x, y)?;
+            let changed = before[(y - viewport.y) as usize * viewport.width + (x - viewport.x) as usize] != n;
+            Some(match n {
+                0 => Cell { ch: '.', style: "2" },
+                _ if changed => Cell { ch: digit(n), style: "1;33" },
+                1 => Cell { ch: '1', style: "36" },
+                _ => Cell { ch: digit(n), style: "1;31" },
+            })
+        });
+        screen.show(&frame, &format!("line {}/{}: {}, {} points with overlaps", i + 1, lines.len(), line, map.overlaps()));
+    }
+    Ok(())
+}
+
+const BASIN_STYLES: [&str; 6] = ["34", "32", "36", "35", "33", "94"];
+
+// basins fill in one at a time, at the end the three largest are highlighted
+fn day09_basins(input: &str, args: &RunArgs, screen: &Screen) -> Result<(), String> {
+    let map = day09::Day09::parse(input).map_err(|e| e.to_string())?;
+    let heights = map.heights();
+    let viewport = args.viewport.unwrap_or_else(|| Viewport::fitting(heights));
+    let mut basin_of: Grid<Option<usize>> = heights.map(|_| None);
+
+    let draw = |basin_of: &Grid<Option<usize>>, largest: &[usize]| render(&viewport, |x, y| {
+        let h = *heights.get(x, y)?;
+        Some(match basin_of.get(x, y).copied().flatten() {
+            Some(b) if largest.contains(&b) => Cell { ch: digit(h), style: "1;97;41" },
+            Some(b) => Cell { ch: digit(h), style: BASIN_STYLES[b % BASIN_STYLES.len()] },
+            None if h == 9 => Cell { ch: '#', style: "2" },
+            None => Cell { ch: digit(h), style: "2" },
+        })
+    });
+
+    let minimums = day09::find_local_minimums(&map);
+    let mut sizes = Vec::new();
+    for (i, min) in minimums.iter().enumerate() {
+        let basin = day09::find_basin(&map, min, day09::WALL_HEIGHT);
+        for c in &basin {
+            let (x, y) = c.position();
+            if let Some(b) = basin_of.get_mut(x, y) {
+                *b = Some(i);
+            }
+        }
+        sizes.push(basin.len());
+        screen.show(&draw(&basin_of, &[]), &format!("basin {}/{}: {} points", i + 1, minimums.len(), basin.len()));
+    }
+
+    let mut by_size: Vec<usize> = (0..sizes.len()).collect();
+    by_size.sort_by_key(|b| std::cmp::Reverse(sizes[*b]));
+    let largest = &by_size[..by_size.len().min(3)];
+    let product: usize = largest.iter().map(|b| sizes[*b]).product();
+    screen.show(&draw(&basin_of, largest), &format!("three largest basins: {:?}, product {}", largest.iter().map(|b| sizes[*b]).collect::<Vec<_>>(), product));
+    Ok(())
+}
+
+// one frame per step until all octopuses flash together, 100 steps for part 1
+fn day11_octopuses(input: &str, args: &RunArgs, screen: &Screen) -> Result<(), String> {
+    let energies = day11::Day11::parse(input).map_err(|e| e.to_string())?;
+    let mut map = day11::Map::new(&energies);
+    let viewport = args.viewport.unwrap_or_else(|| Viewport::fitting(&energies));
+    let steps = if args.part == Some(1) { 100 } else { 10000 };
+
+    for _ in 0..steps {
+        let all_flashed = map.step();
+        let energies = map.energies();
+        let frame = render(&viewport, |x, y| {
+            Some(match *energies.get(x, y)? {
+                0 => Cell { ch: '0', style: "1;93" },
+                e => Cell { ch: digit(e), style: if e >= 7 { "37" } else { "90" } },
+            })
+        });
+        screen.show(&frame, &format!("step {}, {} flashes so far", map.step, map.total_flashes_number));
+        if all_flashed {
+            println!("all octopuses flashed together in step {}", map.step);
+            break;
+        }
+    }
+    Ok(())
+}
+
+fn digit(n: i32) -> char {
+    char::from_digit(n.clamp(0, 9) as u32, 10).unwrap_or('?')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_viewports() {
+        assert_eq!(Viewport::parse("10, 20,30,5"), Ok(Viewport { x: 10, y: 20, width: 30, height: 5 }));
+        assert!(Viewport::parse("1,2,3").is_err());
+        assert!(Viewport::parse("1,2,0,3").is_err());
+    }
+
+    #[test]
+    fn renders_only_the_viewport() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        let viewport = Viewport { x: 1, y: 1, width: 3, height: 1 };
+        let frame = render(&viewport, |x, y| grid.get(x, y).map(|n| Cell { ch: digit(*n), style: if *n == 5 { "1" } else { "" } }));
+        assert_eq!(frame, "\x1b[0m\x1b[1m5\x1b[0m6 \n");
+    }
+}
@@ -0,0 +1,460 @@
+//! day 1, sonar sweep depth increases. the batch functions work on parsed depths, DipCounter
+//! and DepthMonitor count while the depths come in and depth_report describes a whole series
+
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use aoc_common::options::{parse_option, unknown_option};
+use aoc_common::{for_each_line, parse_token, trace, Options, ParseError, Solution, Streaming};
+
+/// the window part 2 sums over, 3 in the puzzle
+pub struct Day01Options {
+    // part 2 counts the increases of the sums over this many depths
+    pub window: usize,
+}
+
+impl Default for Day01Options {
+    fn default() -> Self {
+        Day01Options { window: 3 }
+    }
+}
+
+impl Options for Day01Options {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "window" => self.window = parse_option(key, value)?,
+            _ => return Err(unknown_option(key, self)),
+        }
+        Ok(())
+    }
+
+    fn values(&self) -> Vec<(&'static str, String)> {
+        vec![("window", self.window.to_string())]
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.window == 0 {
+            return Err("window has to be at least 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// parses one depth per line
+pub fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    let mut depths = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let line_int: i64 = parse_token(i + 1, line, line.trim())?;
+        depths.push(line_int);
+        trace!("depth {}", line_int);
+    }
+
+    Ok(depths)
+}
+
+/// the number of depths deeper than the one before, 0 for less than two depths
+pub fn count_dips(depths: &[i64]) -> i64 {
+    depths.windows(2).filter(|w| w[0] < w[1]).count() as i64
+}
+
+/// the sums of every 3 consecutive depths, the puzzle's part 2 windows
+pub fn calculate_3_windows_depths(depths: &[i64]) -> Vec<i64> {
+    window_sums(depths, 3)
+}
+
+/// sums of every k consecutive depths with a running sum, empty when there are less than k depths (or k is 0)
+pub fn window_sums(depths: &[i64], k: usize) -> Vec<i64> {
+    if k == 0 || depths.len() < k {
+        return Vec::new();
+    }
+    let mut sum: i64 = depths[..k].iter().sum();
+    let mut sums = Vec::with_capacity(depths.len() - k + 1);
+    sums.push(sum);
+    for i in k..depths.len() {
+        sum += depths[i] - depths[i - k];
+        sums.push(sum);
+    }
+    sums
+}
+
+/// the dips of the window sums for every k in one pass, same as count_dips(&window_sums(depths, k)).
+// the next k-window sum is bigger exactly when the depth entering it is deeper than the one leaving it
+pub fn count_window_dips(depths: &[i64], sizes: &[usize]) -> Vec<i64> {
+    let mut dips = vec![0; sizes.len()];
+    for (i, depth) in depths.iter().enumerate() {
+        for (k, d) in sizes.iter().zip(dips.iter_mut()) {
+            if *k > 0 && i >= *k && *depth > depths[i - k] {
+                *d += 1;
+            }
+        }
+    }
+    dips
+}
+
+/// which way a Run of depths goes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Increasing,
+    Decreasing,
+}
+
+/// the depths from start to end, both included, all go the same way
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    pub start: usize,
+    pub end: usize,
+    pub trend: Trend,
+}
+
+/// the change from the depth at index - 1 to the one at index
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Jump {
+    pub index: usize,
+    pub change: i64,
+}
+
+/// statistics of a series of depths, made by depth_report and printed by its Display
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DepthReport {
+    pub depths: usize,
+    pub increases: usize,
+    pub decreases: usize,
+    pub plateaus: usize,
+    // the first of the longest, None when the depths never change
+    pub longest_run: Option<Run>,
+    // the first of the largest by absolute change
+    pub largest_jump: Option<Jump>,
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+    pub mean: Option<f64>,
+    // every index whose depth is deeper than the one before, count_dips is their number
+    pub increase_indices: Vec<usize>,
+}
+
+/// everything in one pass over the depths
+pub fn depth_report(depths: &[i64]) -> DepthReport {
+    let mut report = DepthReport { depths: depths.len(), ..DepthReport::default() };
+    let mut sum = 0.0;
+
+    let mut run: Option<Run> = None;
+    for (i, depth) in depths.iter().copied().enumerate() {
+        report.min = Some(report.min.map_or(depth, |m| m.min(depth)));
+        report.max = Some(report.max.map_or(depth, |m| m.max(depth)));
+        sum += depth as f64;
+        if i == 0 {
+            continue;
+        }
+
+        let change = depth - depths[i - 1];
+        let trend = match change.cmp(&0) {
+            std::cmp::Ordering::Greater => Some(Trend::Increasing),
+            std::cmp::Ordering::Less => Some(Trend::Decreasing),
+            std::cmp::Ordering::Equal => None,
+        };
+        match trend {
+            Some(Trend::Increasing) => {
+                report.increases += 1;
+                report.increase_indices.push(i);
+            }
+            Some(Trend::Decreasing) => report.decreases += 1,
+            None => report.plateaus += 1,
+        }
+
+        if report.largest_jump.is_none_or(|j| change.abs() > j.change.abs()) {
+            report.largest_jump = Some(Jump { index: i, change });
+        }
+
+        run = match (run, trend) {
+            (Some(r), Some(t)) if r.trend == t => Some(Run { end: i, ..r }),
+            (_, t) => t.map(|trend| Run { start: i - 1, end: i, trend }),
+        };
+        if let Some(r) = run {
+            if report.longest_run.is_none_or(|l| r.end - r.start > l.end - l.start) {
+                report.longest_run = Some(r);
+            }
+        }
+    }
+
+    report.mean = (!depths.is_empty()).then(|| sum / depths.len() as f64);
+    report
+}
+
+impl Display for Trend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Trend::Increasing => write!(f, "increasing"),
+            Trend::Decreasing => write!(f, "decreasing"),
+        }
+    }
+}
+
+impl Display for DepthReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "depths: {}", self.depths)?;
+        writeln!(f, "increases: {}, decreases: {}, plateaus: {}", self.increases, self.decreases, self.plateaus)?;
+        match self.longest_run {
+            Some(r) => writeln!(f, "longest run: {} from index {} to {}, {} steps", r.trend, r.start, r.end, r.end - r.start)?,
+            None => writeln!(f, "longest run: none")?,
+        }
+        match self.largest_jump {
+            Some(j) => writeln!(f, "largest jump: {:+} at index {}", j.change, j.index)?,
+            None => writeln!(f, "largest jump: none")?,
+        }
+        match (self.min, self.max, self.mean) {
+            (Some(min), Some(max), Some(mean)) => writeln!(f, "min: {}, max: {}, mean: {:.2}", min, max, mean)?,
+            _ => writeln!(f, "min: -, max: -, mean: -")?,
+        }
+        let indices: Vec<String> = self.increase_indices.iter().map(|i| i.to_string()).collect();
+        writeln!(f, "increases at: {}", indices.join(" "))
+    }
+}
+
+/// counts the dips while the depths come in, only the last window of depths is kept.
+/// the state Day01 streams with
+pub struct DipCounter {
+    last: VecDeque<i64>,
+    window: usize,
+    previous: Option<i64>,
+    pub dips: i64,
+    pub window_dips: i64,
+}
+
+impl DipCounter {
+    /// part 2 compares sums over window depths
+    pub fn new(window: usize) -> DipCounter {
+        DipCounter { last: VecDeque::with_capacity(window + 1), window, previous: None, dips: 0, window_dips: 0 }
+    }
+
+    /// counts the depth against the previous one and the one leaving the window
+    pub fn push(&mut self, depth: i64) {
+        if self.previous.is_some_and(|p| depth > p) {
+            self.dips += 1;
+        }
+        self.previous = Some(depth);
+
+        self.last.push_back(depth);
+        if self.last.len() > self.window {
+            let leaving = self.last.pop_front().unwrap_or_default();
+            if depth > leaving {
+                self.window_dips += 1;
+            }
+        }
+    }
+}
+
+impl Default for DipCounter {
+    fn default() -> Self {
+        DipCounter::new(Day01Options::default().window)
+    }
+}
+
+/// the live state of a feed of depths: the counters, the latest depth and the latest window sum.
+/// like DipCounter it keeps only the last window of depths, so it can watch an endless feed
+pub struct DepthMonitor {
+    counter: DipCounter,
+    readings: usize,
+}
+
+impl DepthMonitor {
+    /// nothing read yet, window_sum sums over window depths
+    pub fn new(window: usize) -> DepthMonitor {
+        DepthMonitor { counter: DipCounter::new(window), readings: 0 }
+    }
+
+    /// one more reading
+    pub fn push(&mut self, depth: i64) {
+        self.counter.push(depth);
+        self.readings += 1;
+    }
+
+    /// pushes every depth read from the reader and calls on_reading after each one
+    pub fn watch<R: BufRead>(&mut self, reader: R, mut on_reading: impl FnMut(&DepthMonitor)) -> Result<(), Box<dyn Error>> {
+        for_each_line(reader, |line_no, line| {
+            self.push(parse_token(line_no, line, line.trim())?);
+            on_reading(self);
+            Ok::<(), Box<dyn Error>>(())
+        })
+    }
+
+    /// the number of depths pushed so far
+    pub fn readings(&self) -> usize {
+        self.readings
+    }
+
+    /// the latest depth
+    pub fn current(&self) -> Option<i64> {
+        self.counter.previous
+    }
+
+    /// the sum of the latest window, None until a whole window has been read
+    pub fn window_sum(&self) -> Option<i64> {
+        let last = &self.counter.last;
+        (last.len() == self.counter.window).then(|| last.iter().sum())
+    }
+
+    /// the part 1 answer so far
+    pub fn increases(&self) -> i64 {
+        self.counter.dips
+    }
+
+    /// the part 2 answer so far
+    pub fn window_increases(&self) -> i64 {
+        self.counter.window_dips
+    }
+}
+
+impl Default for DepthMonitor {
+    fn default() -> Self {
+        DepthMonitor::new(Day01Options::default().window)
+    }
+}
+
+impl Display for DepthMonitor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let show = |value: Option<i64>| value.map_or("-".to_string(), |v| v.to_string());
+        write!(
+            f,
+            "reading {}: depth {}, window sum {}, increases {}, window increases {}",
+            self.readings,
+            show(self.current()),
+            show(self.window_sum()),
+            self.increases(),
+            self.window_increases()
+        )
+    }
+}
+
+/// the Solution of day 1, it can also be streamed
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Sonar Sweep";
+
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+    type Options = Day01Options;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(depths: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(count_dips(depths))
+    }
+
+    fn part2(depths: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        Self::part2_with(depths, &Day01Options::default())
+    }
+
+    fn part2_with(depths: &Self::Input, options: &Day01Options) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(count_dips(&window_sums(depths, options.window)))
+    }
+}
+
+impl Streaming for Day01 {
+    type State = DipCounter;
+
+    fn start(options: &Day01Options) -> DipCounter {
+        DipCounter::new(options.window)
+    }
+
+    fn feed(counter: &mut DipCounter, line_no: usize, line: &str) -> Result<(), ParseError> {
+        counter.push(parse_token(line_no, line, line.trim())?);
+        Ok(())
+    }
+
+    fn finish1(counter: &DipCounter) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(counter.dips)
+    }
+
+    fn finish2(counter: &DipCounter) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(counter.window_dips)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn example_part1() {
+        let input = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(Day01::part1(&input).unwrap(), 7);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(Day01::part2(&input).unwrap(), 5);
+    }
+
+    #[test]
+    fn streams_the_example() {
+        let counter = aoc_common::stream::<Day01, _>(EXAMPLE.as_bytes(), &Day01Options::default()).unwrap();
+        assert_eq!((Day01::finish1(&counter).unwrap(), Day01::finish2(&counter).unwrap()), (7, 5));
+    }
+
+    #[test]
+    fn windows_of_any_size() {
+        let depths = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(window_sums(&depths, 3), calculate_3_windows_depths(&depths));
+        assert_eq!(window_sums(&depths, 1), depths);
+        assert_eq!(window_sums(&[1, 2, 3, 4], 2), vec![3, 5, 7]);
+
+        let sizes = [1, 2, 3, 5, 10, 11, 0];
+        let expected: Vec<i64> = sizes.iter().map(|k| count_dips(&window_sums(&depths, *k))).collect();
+        assert_eq!(count_window_dips(&depths, &sizes), expected);
+        assert_eq!(&expected[..3], &[7, 5, 5]);
+    }
+
+    #[test]
+    fn example_report() {
+        let report = depth_report(&Day01::parse(EXAMPLE).unwrap());
+        assert_eq!((report.increases, report.decreases, report.plateaus), (7, 2, 0));
+        // 0..=3 and 4..=7 are both 3 steps up, the first one wins
+        assert_eq!(report.longest_run, Some(Run { start: 0, end: 3, trend: Trend::Increasing }));
+        assert_eq!(report.largest_jump, Some(Jump { index: 6, change: 33 }));
+        assert_eq!((report.min, report.max, report.mean), (Some(199), Some(269), Some(225.6)));
+        assert_eq!(report.increase_indices, vec![1, 2, 3, 5, 6, 7, 9]);
+
+        let flat = depth_report(&[4, 4, 3]);
+        assert_eq!((flat.plateaus, flat.longest_run.map(|r| r.trend)), (1, Some(Trend::Decreasing)));
+        assert_eq!(depth_report(&[]).longest_run, None);
+    }
+
+    #[test]
+    fn monitors_the_example() {
+        let mut monitor = DepthMonitor::default();
+        assert_eq!((monitor.current(), monitor.window_sum()), (None, None));
+
+        let mut seen = Vec::new();
+        monitor.watch(EXAMPLE.as_bytes(), |m| seen.push((m.increases(), m.window_increases()))).unwrap();
+        assert_eq!(seen.len(), 10);
+        // the counters after each reading match the batch answers for the depths read so far
+        let depths = Day01::parse(EXAMPLE).unwrap();
+        for (n, counts) in seen.iter().enumerate() {
+            let so_far = &depths[..=n];
+            assert_eq!(*counts, (count_dips(so_far), count_dips(&window_sums(so_far, 3))));
+        }
+        assert_eq!((monitor.current(), monitor.window_sum()), (Some(263), Some(792)));
+        assert_eq!(monitor.to_string(), "reading 10: depth 263, window sum 792, increases 7, window increases 5");
+
+        assert!(DepthMonitor::default().watch("1\nx\n".as_bytes(), |_| {}).is_err());
+    }
+
+    #[test]
+    fn short_and_empty_series() {
+        for depths in [&[][..], &[5], &[5, 6]] {
+            assert!(window_sums(depths, 3).is_empty());
+            assert_eq!(Day01::part2(&depths.to_vec()).unwrap(), 0);
+        }
+        assert_eq!(count_dips(&[]), 0);
+        assert_eq!(Day01::part1(&vec![5, 6]).unwrap(), 1);
+        assert_eq!(count_window_dips(&[], &[1, 3]), vec![0, 0]);
+    }
+}
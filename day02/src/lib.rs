@@ -0,0 +1,168 @@
+//! day 2, steering the submarine. commands can be parsed or built directly and are applied
+//! to a Submarine, which moves the positions of both parts at once
+
+use std::error::Error;
+use aoc_common::{parse_token, ParseError, Solution, Streaming};
+
+/// parses one command per line
+pub fn parse_input(input: &str) -> Result<Vec<Command>, ParseError> {
+    let mut commands = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        commands.push(parse_command(i + 1, line)?);
+    }
+
+    Ok(commands)
+}
+
+/// parses 'forward 5', 'down 3' or 'up 2'
+pub fn parse_command(line_no: usize, line: &str) -> Result<Command, ParseError> {
+    let mut split_iter = line.split(' ');
+    let command_str = split_iter.next().unwrap_or_default();
+    let amount_str = split_iter.next().ok_or_else(|| ParseError::new(line_no, line.len() + 1, line, "couldn't parse amount"))?;
+    if let Some(extra) = split_iter.next() {
+        return Err(ParseError::at(line_no, line, extra, "unexpected text after amount"));
+    }
+    let amount = parse_token(line_no, line, amount_str)?;
+    match command_str {
+        "forward" => Ok(Command::Forward(amount)),
+        "down" => Ok(Command::Down(amount)),
+        "up" => Ok(Command::Up(amount)),
+        _ => Err(ParseError::at(line_no, line, command_str, "unexpected command")),
+    }
+}
+
+/// a command with its amount
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Forward(i64),
+    Up(i64),
+    Down(i64),
+}
+
+/// how far forward and how deep the submarine is
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Position {
+    pub forward: i64,
+    pub depth: i64,
+}
+
+/// both parts' positions, moved one command at a time. the state Day02 streams with
+#[derive(Default)]
+pub struct Submarine {
+    pub part1: Position,
+    pub part2: Position,
+    aim: i64,
+}
+
+impl Submarine {
+    /// part 1 reads down and up as moves, part 2 as changes of the aim
+    pub fn apply(&mut self, cmd: &Command) {
+        match cmd {
+            Command::Forward(x) => {
+                self.part1.forward += x;
+                self.part2.forward += x;
+                self.part2.depth += self.aim * x;
+            }
+            Command::Down(d) => {
+                self.part1.depth += d;
+                self.aim += d;
+            }
+            Command::Up(u) => {
+                self.part1.depth -= u;
+                self.aim -= u;
+            }
+        }
+    }
+
+    /// the part 2 aim, down minus up so far
+    pub fn aim(&self) -> i64 {
+        self.aim
+    }
+}
+
+/// where the commands lead when down and up move the submarine
+pub fn position_after_commands_part1(commands: &[Command]) -> Position {
+    let mut submarine = Submarine::default();
+    commands.iter().for_each(|c| submarine.apply(c));
+    submarine.part1
+}
+
+/// where the commands lead when down and up change the aim
+pub fn position_after_commands_part2(commands: &[Command]) -> Position {
+    let mut submarine = Submarine::default();
+    commands.iter().for_each(|c| submarine.apply(c));
+    submarine.part2
+}
+
+/// the Solution of day 2, both parts multiply the final forward and depth. it can also be streamed
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    const TITLE: &'static str = "Dive!";
+
+    type Input = Vec<Command>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+    type Options = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(commands: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let pos = position_after_commands_part1(commands);
+        Ok(pos.forward * pos.depth)
+    }
+
+    fn part2(commands: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let pos = position_after_commands_part2(commands);
+        Ok(pos.forward * pos.depth)
+    }
+}
+
+impl Streaming for Day02 {
+    type State = Submarine;
+
+    fn start(_options: &()) -> Submarine {
+        Submarine::default()
+    }
+
+    fn feed(submarine: &mut Submarine, line_no: usize, line: &str) -> Result<(), ParseError> {
+        submarine.apply(&parse_command(line_no, line)?);
+        Ok(())
+    }
+
+    fn finish1(submarine: &Submarine) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(submarine.part1.forward * submarine.part1.depth)
+    }
+
+    fn finish2(submarine: &Submarine) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(submarine.part2.forward * submarine.part2.depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn example_part1() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(Day02::part1(&input).unwrap(), 150);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(Day02::part2(&input).unwrap(), 900);
+    }
+
+    #[test]
+    fn streams_the_example() {
+        let submarine = aoc_common::stream::<Day02, _>(EXAMPLE.as_bytes(), &()).unwrap();
+        assert_eq!((Day02::finish1(&submarine).unwrap(), Day02::finish2(&submarine).unwrap()), (150, 900));
+    }
+}
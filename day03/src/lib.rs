@@ -0,0 +1,266 @@
+//! day 3, binary diagnostic. every line is a bitset of 0s and 1s, part 1 only needs the
+//! ColumnCounts of the ones while part 2 filters the bitsets down to the two ratings
+
+use std::error::Error;
+use aoc_common::{ParseError, Solution, Streaming};
+
+/// parses one bitset per line, every line has as many bits as the first
+pub fn parse_input(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    let mut lines_bits: Vec<Vec<u32>> = Vec::new();
+
+    for (i, l) in input.lines().enumerate() {
+        let line_bits = parse_bits(i + 1, l, lines_bits.first().map(|f| f.len()))?;
+        lines_bits.push(line_bits);
+    }
+
+    Ok(lines_bits)
+}
+
+/// the widest line that can be parsed, two ratings of this many bits still multiply within a u64
+pub const MAX_BITS: usize = 32;
+
+/// parses one line of 0s and 1s, width is the number of bits it has to have, None for any up to MAX_BITS
+pub fn parse_bits(line_no: usize, l: &str, width: Option<usize>) -> Result<Vec<u32>, ParseError> {
+    let mut line_bits: Vec<u32> = Vec::new();
+    for (col, c) in l.chars().enumerate() {
+        let bit = match c {
+            '0' => 0,
+            '1' => 1,
+            _ => return Err(ParseError::new(line_no, col + 1, c, "unexpected character")),
+        };
+        line_bits.push(bit);
+    }
+    if line_bits.len() > MAX_BITS {
+        return Err(ParseError::new(line_no, 1, l, format!("expected at most {} bits, found {}", MAX_BITS, line_bits.len())));
+    }
+    if let Some(width) = width {
+        if line_bits.len() != width {
+            return Err(ParseError::new(line_no, 1, l, format!("expected {} bits, found {}", width, line_bits.len())));
+        }
+    }
+    Ok(line_bits)
+}
+
+/// keeps the bitsets with the most common bit, 1 on a tie, column by column until one is left
+pub fn calculate_oxygen_generator_rating(bitset_lines: &[Vec<u32>]) -> Result<u64, String> {
+    let mut bitsets_left = bitset_lines.to_vec();
+    for pos in 0..bit_width(bitset_lines) {
+        let (ones, zeros) = calculate_ones_and_zeroes_sums_on_position_in_list_of_bitsets(&bitsets_left, pos);
+        if zeros > ones {
+            bitsets_left.retain(|bs| bs[pos] == 0);
+        } else {
+            bitsets_left.retain(|bs| bs[pos] == 1);
+        }
+        if bitsets_left.len() == 1 {
+            return Ok(bitset_to_number(&bitsets_left[0]));
+        }
+    }
+
+    Err(format!("expected to find oxygen generator rating, but there are still {} bitsets left", bitsets_left.len()))
+}
+
+/// keeps the bitsets with the least common bit, 0 on a tie, column by column until one is left
+pub fn calculate_co2_scrubber_rating(bitset_lines: &[Vec<u32>]) -> Result<u64, String> {
+    let mut bitsets_left = bitset_lines.to_vec();
+    for pos in 0..bit_width(bitset_lines) {
+        let (ones, zeros) = calculate_ones_and_zeroes_sums_on_position_in_list_of_bitsets(&bitsets_left, pos);
+        // when all the remaining bitsets agree there is no less common bit to keep
+        if ones == 0 || zeros == 0 {
+            continue;
+        }
+        if zeros <= ones {
+            bitsets_left.retain(|bs| bs[pos] == 0);
+        } else {
+            bitsets_left.retain(|bs| bs[pos] == 1);
+        }
+        if bitsets_left.len() == 1 {
+            return Ok(bitset_to_number(&bitsets_left[0]));
+        }
+    }
+
+    Err(format!("expected to find co2 scrubber rating, but there are still {} bitsets left", bitsets_left.len()))
+}
+
+/// gamma has the most common bit of every column, epsilon the least common one
+pub fn calculate_gamma_and_epsilon(bitset_lines: &[Vec<u32>]) -> (u64, u64) {
+    let mut counts = ColumnCounts::default();
+    bitset_lines.iter().for_each(|bs| counts.add(bs));
+    counts.gamma_and_epsilon()
+}
+
+/// the number of ones in every column, enough for part 1 without keeping the bitsets.
+/// the state Day03 streams part 1 with
+#[derive(Default)]
+pub struct ColumnCounts {
+    pub ones: Vec<u64>,
+    pub lines: u64,
+    // the bits of the first line, every other line has to have as many. None before the first line
+    pub width: Option<usize>,
+}
+
+impl ColumnCounts {
+    /// counts one more bitset, the first one sets the width
+    pub fn add(&mut self, bits: &[u32]) {
+        if self.width.is_none() {
+            self.width = Some(bits.len());
+            self.ones = vec![0; bits.len()];
+        }
+        for (count, bit) in self.ones.iter_mut().zip(bits) {
+            *count += *bit as u64;
+        }
+        self.lines += 1;
+    }
+
+    /// same as calculate_gamma_and_epsilon on the bitsets added so far
+    pub fn gamma_and_epsilon(&self) -> (u64, u64) {
+        let mut gamma_bits = Vec::new();
+        let mut epsilon_bits = Vec::new();
+
+        for ones in &self.ones {
+            let zeros = self.lines - ones;
+            if *ones > zeros {
+                gamma_bits.push(1);
+                epsilon_bits.push(0);
+            } else {
+                gamma_bits.push(0);
+                epsilon_bits.push(1)
+            }
+        }
+
+        let g = bitset_to_number(&gamma_bits);
+        let e = bitset_to_number(&epsilon_bits);
+
+        (g,e)
+    }
+}
+
+fn calculate_ones_and_zeroes_sums_on_position_in_list_of_bitsets(bs_list: &[Vec<u32>], pos: usize) -> (u32, u32) {
+    let mut ones = 0;
+    let mut zeroes = 0;
+    for bs in bs_list {
+        if bs[pos] == 1 {
+            ones += 1;
+        } else {
+            zeroes += 1;
+        }
+    }
+
+    (ones, zeroes)
+}
+
+fn bit_width(bitset_lines: &[Vec<u32>]) -> usize {
+    bitset_lines.first().map_or(0, |l| l.len())
+}
+
+/// the bits as a number, the first one is the most significant
+pub fn bitset_to_number(in_vec: &[u32]) -> u64 {
+    let mut o = 0;
+    for (i, b) in in_vec.iter().enumerate() {
+        o |= (*b as u64) << (in_vec.len() - 1 - i);
+    }
+    o
+}
+
+/// the Solution of day 3, only part 1 can be streamed
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    const TITLE: &'static str = "Binary Diagnostic";
+
+    type Input = Vec<Vec<u32>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Options = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(bitsets_lines: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let (e, g) = calculate_gamma_and_epsilon(bitsets_lines);
+        Ok(e * g)
+    }
+
+    fn part2(bitsets_lines: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let o2_gen_rating = calculate_oxygen_generator_rating(bitsets_lines)?;
+        let co2_scrubber_rating = calculate_co2_scrubber_rating(bitsets_lines)?;
+        Ok(o2_gen_rating * co2_scrubber_rating)
+    }
+}
+
+impl Streaming for Day03 {
+    type State = ColumnCounts;
+    const STREAMED_PARTS: &'static [u32] = &[1];
+
+    fn start(_options: &()) -> ColumnCounts {
+        ColumnCounts::default()
+    }
+
+    fn feed(counts: &mut ColumnCounts, line_no: usize, line: &str) -> Result<(), ParseError> {
+        counts.add(&parse_bits(line_no, line, counts.width)?);
+        Ok(())
+    }
+
+    fn finish1(counts: &ColumnCounts) -> Result<Self::Answer1, Box<dyn Error>> {
+        let (e, g) = counts.gamma_and_epsilon();
+        Ok(e * g)
+    }
+
+    // the ratings filter the bitsets again and again, the counts aren't enough
+    fn finish2(_counts: &ColumnCounts) -> Result<Self::Answer2, Box<dyn Error>> {
+        Err("part 2 needs all the bitsets and can't be streamed".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn example_part1() {
+        let input = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(Day03::part1(&input).unwrap(), 198);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(Day03::part2(&input).unwrap(), 230);
+    }
+
+    #[test]
+    fn part2_when_all_remaining_share_a_bit() {
+        let input = Day03::parse("11\n10\n").unwrap();
+        assert_eq!(Day03::part2(&input).unwrap(), 3 * 2);
+    }
+
+    #[test]
+    fn wide_lines() {
+        let ones = "1".repeat(MAX_BITS);
+        let input = Day03::parse(&format!("{}\n0{}\n", ones, &ones[1..])).unwrap();
+        // the first column is a tie, so gamma gets a 0 there and epsilon a 1
+        assert_eq!(Day03::part1(&input).unwrap(), 0x7FFF_FFFF * 0x8000_0000);
+        assert_eq!(Day03::part2(&input).unwrap(), 0xFFFF_FFFF * 0x7FFF_FFFF);
+        assert!(Day03::parse(&"1".repeat(MAX_BITS + 1)).is_err());
+    }
+
+    #[test]
+    fn streams_the_example() {
+        let counts = aoc_common::stream::<Day03, _>(EXAMPLE.as_bytes(), &()).unwrap();
+        assert_eq!(Day03::finish1(&counts).unwrap(), 198);
+        assert!(Day03::finish2(&counts).is_err());
+        assert!(aoc_common::stream::<Day03, _>("101\n10\n".as_bytes(), &()).is_err());
+    }
+
+    #[test]
+    fn stream_and_batch_agree_on_a_leading_blank_line() {
+        for input in ["\n101\n", "\n\n", "101\n\n"] {
+            let streamed = aoc_common::stream::<Day03, _>(input.as_bytes(), &());
+            assert_eq!(streamed.is_ok(), Day03::parse(input).is_ok(), "{:?}", input);
+        }
+        assert!(Day03::parse("\n101\n").is_err());
+    }
+}
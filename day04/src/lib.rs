@@ -0,0 +1,279 @@
+//! day 4, bingo with a giant squid. boards can be parsed with parse_input or built
+//! with BingoBoard::new, find_winning_boards then plays the drawn numbers on them
+
+use std::error::Error;
+use std::fmt::Formatter;
+use aoc_common::options::{parse_option, unknown_option};
+use aoc_common::{parse_comma_separated, parse_token, trace, Grid, Options, ParseError, Solution};
+
+/// the board size, 5 in the puzzle. set with --set board_size=N
+pub struct Day04Options {
+    // boards are board_size x board_size numbers
+    pub board_size: usize,
+}
+
+impl Default for Day04Options {
+    fn default() -> Self {
+        Day04Options { board_size: 5 }
+    }
+}
+
+impl Options for Day04Options {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "board_size" => self.board_size = parse_option(key, value)?,
+            _ => return Err(unknown_option(key, self)),
+        }
+        Ok(())
+    }
+
+    fn values(&self) -> Vec<(&'static str, String)> {
+        vec![("board_size", self.board_size.to_string())]
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.board_size == 0 {
+            return Err("board_size has to be at least 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// a square (or any rectangular) board and the numbers marked on it so far
+#[derive(Clone)]
+pub struct BingoBoard {
+    board_numbers: Grid<u32>,
+    marked_numbers: Grid<bool>,
+}
+
+impl BingoBoard {
+    /// a board with nothing marked yet
+    pub fn new(board_numbers: Grid<u32>) -> BingoBoard {
+        BingoBoard {
+            marked_numbers: Grid::new(board_numbers.width(), board_numbers.height(), false),
+            board_numbers,
+        }
+    }
+
+    /// same as new, from rows of numbers. None if the rows differ in length
+    pub fn from_rows(rows: Vec<Vec<u32>>) -> Option<BingoBoard> {
+        Grid::from_rows(rows).map(BingoBoard::new)
+    }
+
+    /// marks the number, returns Some with the numbers of victory row/column
+    pub fn draw_number(&mut self, n: u32) -> Option<Vec<u32>> {
+        self.mark_numbers(n);
+
+        if let Some(r) = self.find_winning_row() {
+            return Some(r);
+        }
+
+        if let Some(c) = self.find_winning_column() {
+            return Some(c);
+        }
+
+        None
+    }
+
+    fn mark_numbers(&mut self, n: u32) {
+        for (number, marked) in self.board_numbers.iter().zip(self.marked_numbers.iter_mut()) {
+            if *number == n {
+                *marked = true;
+            }
+        }
+    }
+
+    /// the board's numbers
+    pub fn numbers(&self) -> &Grid<u32> {
+        &self.board_numbers
+    }
+
+    /// true for the numbers drawn so far
+    pub fn marked(&self) -> &Grid<bool> {
+        &self.marked_numbers
+    }
+
+    /// the score of a winning board is this times the number that made it win
+    pub fn sum_of_unmarked_numbers(&self) -> u32 {
+        let mut sum = 0;
+        for (number, marked) in self.board_numbers.iter().zip(self.marked_numbers.iter()) {
+            if !marked {
+                sum += number;
+            }
+        }
+
+        sum
+    }
+
+    fn find_winning_row(&self) -> Option<Vec<u32>> {
+        for y in 0..self.board_numbers.height() {
+            if self.marked_numbers.row(y).iter().all(|m| *m) {
+                return Some(self.board_numbers.row(y).to_vec());
+            }
+        }
+
+        None
+    }
+
+    fn find_winning_column(&self) -> Option<Vec<u32>> {
+        for x in 0..self.board_numbers.width() {
+            if self.marked_numbers.column(x).all(|m| *m) {
+                return Some(self.board_numbers.column(x).copied().collect());
+            }
+        }
+
+        None
+    }
+}
+
+impl std::fmt::Display for BingoBoard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (numbers, marked) in self.board_numbers.rows().zip(self.marked_numbers.rows()) {
+            for (n, m) in numbers.iter().zip(marked) {
+                if *m {
+                    write!(f, "|{:2} ", n)?;
+                } else {
+                    write!(f, "{:2}  ", n)?;
+                }
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// the parsed input: the numbers in the order they are drawn and the boards
+pub struct Bingo {
+    pub drawn_numbers: Vec<u32>,
+    pub boards: Vec<BingoBoard>,
+}
+
+/// parses the drawn numbers line and the board_size x board_size boards after it
+pub fn parse_input(input: &str, board_size: usize) -> Result<Bingo, ParseError> {
+    let mut lines_iter = input.lines().enumerate().map(|(i, l)| (i + 1, l));
+    let (_, drawn_numbers_line) = lines_iter.next().ok_or_else(|| ParseError::new(1, 1, "", "failed to get first line - drawn numbers"))?;
+    let drawn_numbers: Vec<u32> = parse_comma_separated(1, drawn_numbers_line)?;
+
+    let mut boards = Vec::new();
+    let mut last_line_no = 1;
+
+    while let Some((line_no, line)) = lines_iter.next() {
+        if !line.is_empty() {
+            return Err(ParseError::new(line_no, 1, line, "expected an empty line before the board"));
+        }
+
+        let mut board = Vec::new();
+        for i in 0..board_size {
+            let (line_no, line) = lines_iter.next().ok_or_else(|| ParseError::new(line_no + i + 1, 1, "", format!("failed to get bingo line {}", i)))?;
+            let mut line_vec: Vec<u32> = Vec::new();
+            for s in line.split_whitespace() {
+                line_vec.push(parse_token(line_no, line, s)?);
+            }
+            if line_vec.len() != board_size {
+                return Err(ParseError::new(line_no, 1, line, format!("expected {} numbers on a bingo line, found {}", board_size, line_vec.len())));
+            }
+            trace!("got line vec: {:?}", &line_vec);
+            board.push(line_vec);
+            last_line_no = line_no;
+        }
+        let board = Grid::from_rows(board).expect("every bingo line has board_size numbers");
+        boards.push(BingoBoard::new(board));
+    }
+
+    if boards.is_empty() {
+        return Err(ParseError::new(last_line_no + 1, 1, "", "no bingo boards found"));
+    }
+
+    Ok(Bingo { drawn_numbers, boards })
+}
+
+/// plays all the drawn numbers and returns the boards in the order they won, with the winning number
+pub fn find_winning_boards(drawn_numbers: &[u32], mut boards: Vec<BingoBoard>) -> Vec<(BingoBoard, u32)> {
+    let mut winning_boards = Vec::new();
+
+    for n in drawn_numbers.iter().copied() {
+        for i in (0..boards.len()).rev() {
+            if boards[i].draw_number(n).is_some() {
+                let winning_board = boards.swap_remove(i);
+                winning_boards.push((winning_board, n));
+            }
+        }
+    }
+
+    winning_boards
+}
+
+/// the Solution of day 4: part 1 scores the first board to win, part 2 the last one
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    const TITLE: &'static str = "Giant Squid";
+
+    type Input = Bingo;
+    type Answer1 = u32;
+    type Answer2 = u32;
+    type Options = Day04Options;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Day04Options::default())
+    }
+
+    // the board size only matters for parsing, the game works on any grid
+    fn parse_with(input: &str, options: &Day04Options) -> Result<Self::Input, ParseError> {
+        parse_input(input, options.board_size)
+    }
+
+    fn part1(bingo: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let winning_boards = find_winning_boards(&bingo.drawn_numbers, bingo.boards.clone());
+        let (first_board, first_board_draw_number) = winning_boards.first().ok_or("no board has won")?;
+        Ok(first_board.sum_of_unmarked_numbers() * first_board_draw_number)
+    }
+
+    fn part2(bingo: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let winning_boards = find_winning_boards(&bingo.drawn_numbers, bingo.boards.clone());
+        let (last_board, last_board_drawn_number) = winning_boards.last().ok_or("no board has won")?;
+        Ok(last_board.sum_of_unmarked_numbers() * last_board_drawn_number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn example_part1() {
+        let input = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::part1(&input).unwrap(), 4512);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::part2(&input).unwrap(), 1924);
+    }
+
+    #[test]
+    fn smaller_boards() {
+        let input = "3,1,2,4\n\n1 2\n3 4\n\n4 1\n9 2\n";
+        let options = Day04Options { board_size: 2 };
+        let bingo = Day04::parse_with(input, &options).unwrap();
+        // the first board completes its first column 1 3 when 1 is drawn
+        assert_eq!(Day04::part1(&bingo).unwrap(), 2 + 4);
+        assert!(Day04::parse(input).is_err());
+    }
+
+    #[test]
+    fn boards_built_directly() {
+        let boards = vec![
+            BingoBoard::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap(),
+            BingoBoard::from_rows(vec![vec![4, 1], vec![9, 2]]).unwrap(),
+        ];
+        let bingo = Bingo { drawn_numbers: vec![3, 1, 2, 4], boards };
+        assert_eq!(Day04::part1(&bingo).unwrap(), 2 + 4);
+        assert!(BingoBoard::from_rows(vec![vec![1, 2], vec![3]]).is_none());
+    }
+}
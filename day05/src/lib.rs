@@ -0,0 +1,330 @@
+//! day 5, overlapping hydrothermal vent lines. lines can be parsed with parse_input
+//! or built with Line::new and are drawn on a Map to count the overlaps
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use aoc_common::options::{parse_option, unknown_option};
+use aoc_common::{parse_token, Grid, Options, ParseError, Solution};
+
+/// side of the puzzle's square map, every coordinate of the input is below it
+pub const MAP_SIDE: usize = 1000;
+/// the largest map_side that can be set, the map keeps a counter for every point
+pub const MAX_MAP_SIDE: usize = 10_000;
+
+/// the size of the map the lines are drawn on
+pub struct Day05Options {
+    // the map is a square, every coordinate has to be below this
+    pub map_side: usize,
+}
+
+impl Default for Day05Options {
+    fn default() -> Self {
+        Day05Options { map_side: MAP_SIDE }
+    }
+}
+
+impl Options for Day05Options {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "map_side" => self.map_side = parse_option(key, value)?,
+            _ => return Err(unknown_option(key, self)),
+        }
+        Ok(())
+    }
+
+    fn values(&self) -> Vec<(&'static str, String)> {
+        vec![("map_side", self.map_side.to_string())]
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.map_side == 0 || self.map_side > MAX_MAP_SIDE {
+            return Err(format!("map_side has to be between 1 and {}", MAX_MAP_SIDE));
+        }
+        Ok(())
+    }
+}
+
+/// a point of the map, x to the right and y down
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Coord {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Coord {
+    pub fn new(x: i32, y: i32) -> Coord {
+        Coord { x, y }
+    }
+}
+
+impl Display for Coord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+/// a vent line from one end to the other, both ends included
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line(pub Coord, pub Coord);
+
+impl Line {
+    pub fn new(from: Coord, to: Coord) -> Line {
+        Line(from, to)
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        self.0.y == self.1.y
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.0.x == self.1.x
+    }
+}
+
+impl Display for Line {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -> {}", self.0, self.1)
+    }
+}
+
+/// counts how many lines cross every point of a square map
+pub struct Map {
+    field: Grid<i32>,
+}
+
+impl Map {
+    /// an empty dim x dim map
+    pub fn new(dim: usize) -> Map {
+        Map {
+            field: Grid::new(dim, dim, 0)
+        }
+    }
+
+    fn mark_point(&mut self, x: i32, y: i32) {
+        // the parser makes sure the lines fit the map
+        if let Some(p) = self.field.get_mut(x, y) {
+            *p += 1;
+        }
+    }
+
+    /// how many lines cross every point
+    pub fn field(&self) -> &Grid<i32> {
+        &self.field
+    }
+
+    /// adds +1 to every 'pixel' that the line hits, the parts outside the map are skipped
+    pub fn mark_line(&mut self, line: &Line) {
+        // find the longer distance
+        let dx = (line.1.x - line.0.x).abs();
+        let dy = (line.1.y - line.0.y).abs();
+
+        if dx >= dy {
+            // iterating along x
+            let x_start;
+            let y_start;
+            let y_inc: f32;
+            if line.1.x > line.0.x {
+                x_start = line.0.x;
+                y_start = line.0.y;
+                y_inc = (line.1.y - line.0.y) as f32 / dx as f32;
+            } else {
+                x_start = line.1.x;
+                y_start = line.1.y;
+                y_inc = (line.0.y - line.1.y) as f32 / dx as f32;
+            }
+
+            let mut y = y_start as f32;
+            for x in x_start..x_start + dx + 1 {
+                self.mark_point(x, y.round() as i32);
+                y += y_inc;
+            }
+        } else {
+            // iterating along y
+            let x_start;
+            let y_start;
+            let x_inc: f32;
+            if line.1.y > line.0.y {
+                x_start = line.0.x;
+                y_start = line.0.y;
+                x_inc = (line.1.x - line.0.x) as f32 / dy as f32;
+            } else {
+                x_start = line.1.x;
+                y_start = line.1.y;
+                x_inc = (line.0.x - line.1.x) as f32 / dy as f32;
+            }
+
+            let mut x = x_start as f32;
+            for y in y_start..y_start + dy + 1 {
+                self.mark_point(x.round() as i32, y);
+                x += x_inc;
+            }
+        }
+    }
+
+    /// the number of points where at least two lines overlap
+    pub fn overlaps(&self) -> usize {
+        self.coords_larger_than_2().len()
+    }
+
+    fn coords_larger_than_2(&self) -> Vec<Coord> {
+        self.field.coords()
+            .filter(|(x, y)| self.field.get(*x, *y).is_some_and(|n| *n >= 2))
+            .map(|(x, y)| Coord { x, y })
+            .collect()
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.field.rows() {
+            for n in row {
+                if *n == 0 {
+                    write!(f, ".")?;
+                } else {
+                    write!(f, "{}", n)?;
+                }
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// parses 'x1,y1 -> x2,y2' lines, every coordinate has to fit a map_side x map_side map
+pub fn parse_input(input: &str, map_side: usize) -> Result<Vec<Line>, ParseError> {
+    let mut lines = Vec::new();
+    for (i, l) in input.lines().enumerate() {
+        let line = parse_line(i + 1, l, map_side)?;
+        lines.push(line);
+    }
+
+    Ok(lines)
+}
+
+/// parses one 'x1,y1 -> x2,y2' line
+pub fn parse_line(line_no: usize, line_str: &str, map_side: usize) -> Result<Line, ParseError> {
+    let split_line: Vec<&str> = line_str.split("->").collect();
+    if split_line.len() != 2 {
+        return Err(ParseError::new(line_no, 1, line_str, format!("expected 'x1,y1 -> x2,y2', found {} parts", split_line.len())));
+    }
+    let c1 = parse_coordinates(line_no, line_str, split_line[0], map_side)?;
+    let c2 = parse_coordinates(line_no, line_str, split_line[1], map_side)?;
+
+    Ok(Line(c1, c2))
+}
+
+fn parse_coordinates(line_no: usize, line_str: &str, coords_str: &str, map_side: usize) -> Result<Coord, ParseError> {
+    let coords_split: Vec<&str> = coords_str.split(',').map(|s| s.trim()).collect();
+    if coords_split.len() != 2 {
+        return Err(ParseError::at(line_no, line_str, coords_str.trim(), format!("expected 'x,y', found {} parts", coords_split.len())));
+    }
+    let x: i32 = parse_token(line_no, line_str, coords_split[0])?;
+    let y: i32 = parse_token(line_no, line_str, coords_split[1])?;
+    for (c, s) in [(x, coords_split[0]), (y, coords_split[1])] {
+        if c < 0 || c as usize >= map_side {
+            return Err(ParseError::at(line_no, line_str, s, format!("coordinate outside of the {}x{} map", map_side, map_side)));
+        }
+    }
+
+    Ok(Coord { x, y })
+}
+
+/// overlaps of the horizontal and vertical lines only
+pub fn part_1(lines: &[Line], map_side: usize) -> usize {
+    let mut map = Map::new(map_side);
+    let horizontal_and_vertical_lines: Vec<&Line> = lines.iter().filter(|l| l.is_horizontal() || l.is_vertical()).collect();
+
+    for l in horizontal_and_vertical_lines.iter() {
+        map.mark_line(l);
+    }
+
+    map.overlaps()
+}
+
+/// overlaps of all the lines, diagonals included
+pub fn part_2(lines: &[Line], map_side: usize) -> usize {
+    let mut map = Map::new(map_side);
+    for l in lines.iter() {
+        map.mark_line(l);
+    }
+
+    map.overlaps()
+}
+
+/// the Solution of day 5
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    const TITLE: &'static str = "Hydrothermal Venture";
+
+    type Input = Vec<Line>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Options = Day05Options;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input, MAP_SIDE)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part_1(lines, MAP_SIDE))
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part_2(lines, MAP_SIDE))
+    }
+
+    fn parse_with(input: &str, options: &Day05Options) -> Result<Self::Input, ParseError> {
+        parse_input(input, options.map_side)
+    }
+
+    fn part1_with(lines: &Self::Input, options: &Day05Options) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part_1(lines, options.map_side))
+    }
+
+    fn part2_with(lines: &Self::Input, options: &Day05Options) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part_2(lines, options.map_side))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn example_part1() {
+        let input = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(Day05::part1(&input).unwrap(), 5);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(Day05::part2(&input).unwrap(), 12);
+    }
+
+    #[test]
+    fn lines_built_directly() {
+        let mut map = Map::new(10);
+        map.mark_line(&Line::new(Coord::new(0, 0), Coord::new(4, 4)));
+        map.mark_line(&Line::new(Coord::new(4, 0), Coord::new(0, 4)));
+        map.mark_line(&Line::new(Coord::new(2, 0), Coord::new(2, 9)));
+        // the diagonals cross at (2,2), which the vertical line also crosses
+        assert_eq!(map.overlaps(), 1);
+        assert_eq!(map.field().get(2, 2), Some(&3));
+    }
+
+    #[test]
+    fn map_side_is_bounded() {
+        let mut options = Day05Options::default();
+        assert!(options.validate().is_ok());
+        for side in ["0", "10001", "100000000000"] {
+            options.set("map_side", side).unwrap();
+            assert!(options.validate().is_err(), "{}", side);
+        }
+    }
+}
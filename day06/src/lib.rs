@@ -0,0 +1,204 @@
+//! day 6, exponentially growing lanternfish. FishPopulation keeps the number of fish
+//! per timer value, so a day of aging takes the same time however many fish there are
+
+use std::error::Error;
+use aoc_common::options::{parse_option, unknown_option};
+use aoc_common::{parse_comma_separated, trace, Options, ParseError, Solution};
+
+/// the largest newborn_timer that can be set, every day copies one slot per timer value
+pub const MAX_TIMER: u64 = 1_000;
+
+/// how many days both parts simulate and the two timers a fish can start from
+pub struct Day06Options {
+    pub days_part1: u32,
+    pub days_part2: u32,
+    // timer of a fish right after it gave birth
+    pub reset_timer: u64,
+    // timer of a newborn fish, the population keeps one slot for every value up to it
+    pub newborn_timer: u64,
+}
+
+impl Default for Day06Options {
+    fn default() -> Self {
+        Day06Options { days_part1: 80, days_part2: 256, reset_timer: 6, newborn_timer: 8 }
+    }
+}
+
+impl Options for Day06Options {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "days_part1" => self.days_part1 = parse_option(key, value)?,
+            "days_part2" => self.days_part2 = parse_option(key, value)?,
+            "reset_timer" => self.reset_timer = parse_option(key, value)?,
+            "newborn_timer" => self.newborn_timer = parse_option(key, value)?,
+            _ => return Err(unknown_option(key, self)),
+        }
+        Ok(())
+    }
+
+    fn values(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("days_part1", self.days_part1.to_string()),
+            ("days_part2", self.days_part2.to_string()),
+            ("reset_timer", self.reset_timer.to_string()),
+            ("newborn_timer", self.newborn_timer.to_string()),
+        ]
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.newborn_timer > MAX_TIMER {
+            return Err(format!("newborn_timer {} can't be larger than {}", self.newborn_timer, MAX_TIMER));
+        }
+        if self.reset_timer > self.newborn_timer {
+            return Err(format!("reset_timer {} can't be larger than newborn_timer {}", self.reset_timer, self.newborn_timer));
+        }
+        Ok(())
+    }
+}
+
+/// the number of fish for every timer value, from 0 to the newborn timer
+pub struct FishPopulation {
+    pub fishes_with_timers: Vec<u64>,
+    reset_timer: usize,
+}
+
+impl FishPopulation {
+    /// an empty population with the puzzle's timers, 6 after spawning and 8 for newborns
+    pub fn new() -> FishPopulation {
+        let options = Day06Options::default();
+        FishPopulation { fishes_with_timers: vec![0; options.newborn_timer as usize + 1], reset_timer: options.reset_timer as usize }
+    }
+    /// an empty population, the timers are checked like the options: reset_timer can't be above
+    /// newborn_timer and newborn_timer can't be above MAX_TIMER
+    pub fn with_timers(reset_timer: u64, newborn_timer: u64) -> Result<FishPopulation, String> {
+        Day06Options { reset_timer, newborn_timer, ..Day06Options::default() }.validate()?;
+        let slots = newborn_timer.checked_add(1).and_then(|n| usize::try_from(n).ok()).ok_or("newborn_timer is too large")?;
+        Ok(FishPopulation { fishes_with_timers: vec![0; slots], reset_timer: reset_timer as usize })
+    }
+    /// panics when the timer is above the newborn timer
+    pub fn add_fish(&mut self, timer: u64) {
+        self.fishes_with_timers[timer as usize] += 1;
+    }
+    /// one day passes, the fish at 0 spawn a newborn each and start over
+    pub fn age(&mut self) {
+        let newborn = self.fishes_with_timers.len() - 1;
+        let mut v = vec![0; newborn + 1];
+        v[..newborn].copy_from_slice(&self.fishes_with_timers[1..]);
+        v[newborn] = self.fishes_with_timers[0];
+        v[self.reset_timer] += self.fishes_with_timers[0];
+        self.fishes_with_timers = v;
+    }
+    pub fn total_number(&self) -> u64 {
+        let mut sum = 0;
+        for n in &self.fishes_with_timers {
+            sum += n;
+        }
+        sum
+    }
+}
+
+impl Default for FishPopulation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// parses the comma separated timers of the initial fish
+pub fn parse_input(input: &str, options: &Day06Options) -> Result<Vec<u64>, ParseError> {
+    let line = input.lines().next().ok_or_else(|| ParseError::new(1, 1, "", "empty input"))?;
+
+    let timers: Vec<u64> = parse_comma_separated(1, line)?;
+    if let Some(pos) = timers.iter().position(|t| *t > options.newborn_timer) {
+        let token = line.trim().split(',').nth(pos).unwrap_or_default().trim();
+        return Err(ParseError::at(1, line, token, format!("fish timer has to be between 0 and {}", options.newborn_timer)));
+    }
+
+    Ok(timers)
+}
+
+/// the number of fish after the given days
+pub fn population_after(initial_fishes: &[u64], days: u32, options: &Day06Options) -> Result<u64, String> {
+    let mut fishes = FishPopulation::with_timers(options.reset_timer, options.newborn_timer)?;
+    initial_fishes.iter().for_each(|t| fishes.add_fish(*t));
+    for day in 1..=days {
+        fishes.age();
+        trace!("day {}: {} fish {:?}", day, fishes.total_number(), fishes.fishes_with_timers);
+    }
+    Ok(fishes.total_number())
+}
+
+/// the Solution of day 6
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    const TITLE: &'static str = "Lanternfish";
+
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+    type Options = Day06Options;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Day06Options::default())
+    }
+
+    fn part1(initial_fishes: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        Self::part1_with(initial_fishes, &Day06Options::default())
+    }
+
+    fn part2(initial_fishes: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        Self::part2_with(initial_fishes, &Day06Options::default())
+    }
+
+    fn parse_with(input: &str, options: &Day06Options) -> Result<Self::Input, ParseError> {
+        parse_input(input, options)
+    }
+
+    fn part1_with(initial_fishes: &Self::Input, options: &Day06Options) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(population_after(initial_fishes, options.days_part1, options)?)
+    }
+
+    fn part2_with(initial_fishes: &Self::Input, options: &Day06Options) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(population_after(initial_fishes, options.days_part2, options)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn example_part1() {
+        let input = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(Day06::part1(&input).unwrap(), 5934);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(Day06::part2(&input).unwrap(), 26984457539);
+    }
+
+    #[test]
+    fn other_timers() {
+        let mut options = Day06Options::default();
+        options.set("days_part1", "18").unwrap();
+        let input = Day06::parse_with(EXAMPLE, &options).unwrap();
+        assert_eq!(Day06::part1_with(&input, &options).unwrap(), 26);
+
+        // fish that never wait just double every day
+        let options = Day06Options { days_part1: 3, reset_timer: 0, newborn_timer: 0, ..Day06Options::default() };
+        assert_eq!(Day06::part1_with(&vec![0, 0], &options).unwrap(), 16);
+        assert!(Day06::parse_with(EXAMPLE, &options).is_err());
+
+        let mut options = Day06Options::default();
+        options.set("newborn_timer", "18446744073709551615").unwrap();
+        assert!(options.validate().is_err());
+        assert!(FishPopulation::with_timers(7, 6).is_err());
+        assert!(FishPopulation::with_timers(u64::MAX, u64::MAX).is_err());
+        assert_eq!(FishPopulation::with_timers(2, 4).unwrap().fishes_with_timers.len(), 5);
+    }
+}
@@ -0,0 +1,116 @@
+//! day 7, aligning the crab submarines. the Solution tries every position, optimal_cost_part_1
+//! and optimal_cost_part_2 find the same answers from the median and the mean
+
+use std::error::Error;
+use aoc_common::{parse_comma_separated, ParseError, Solution};
+
+/// parses the comma separated crab positions
+pub fn parse_input(input: &str) -> Result<Vec<u32>, ParseError> {
+    let line = input.lines().next().ok_or_else(|| ParseError::new(1, 1, "", "empty input"))?;
+    parse_comma_separated(1, line)
+}
+
+/// the fuel to move every crab to pos, one per step
+pub fn cost_of_alignment_part_1(crabs: &[u32], pos: u32) -> i64 {
+    crabs.iter().map(|c| (pos as i64 - *c as i64).abs()).sum()
+}
+
+/// the fuel to move every crab to pos when every step costs one more than the last
+pub fn cost_of_alignment_part_2(crabs: &[u32], pos: u32) -> i64 {
+    let mut sum = 0;
+    for c in crabs {
+        let d = (pos as i64 - *c as i64).abs();
+        let cost = (d * (d + 1)) / 2;
+        sum += cost;
+    }
+    sum
+}
+
+/// cost of aligning on every position between 0 and the rightmost crab, both included
+pub fn costs(crab_positions: &[u32], cost_func: fn(&[u32], u32) -> i64) -> Vec<i64> {
+    let max_position = match crab_positions.iter().max() {
+        Some(m) => *m,
+        None => return Vec::new(),
+    };
+
+    let mut costs = Vec::new();
+    for pos in 0..=max_position {
+        costs.push(cost_func(crab_positions, pos));
+    }
+
+    costs
+}
+
+/// the lowest part 1 cost, the median minimises the sum of distances. None without crabs
+pub fn optimal_cost_part_1(crabs: &[u32]) -> Option<i64> {
+    let mut sorted = crabs.to_vec();
+    sorted.sort_unstable();
+    let median = *sorted.get(sorted.len() / 2)?;
+    Some(cost_of_alignment_part_1(crabs, median))
+}
+
+/// the lowest part 2 cost. None without crabs
+// the triangular cost is minimised within half a step of the mean, so one of its neighbours wins
+pub fn optimal_cost_part_2(crabs: &[u32]) -> Option<i64> {
+    if crabs.is_empty() {
+        return None;
+    }
+    let mean = crabs.iter().map(|c| *c as u64).sum::<u64>() / crabs.len() as u64;
+    let mean = mean as u32;
+    Some(cost_of_alignment_part_2(crabs, mean).min(cost_of_alignment_part_2(crabs, mean + 1)))
+}
+
+/// the Solution of day 7, both parts find the cheapest position
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    const TITLE: &'static str = "The Treachery of Whales";
+
+    type Input = Vec<u32>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+    type Options = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(crab_positions: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let part1_costs = costs(crab_positions, cost_of_alignment_part_1);
+        let part1_min_cost = part1_costs.iter().min().ok_or("no crabs")?;
+        Ok(*part1_min_cost)
+    }
+
+    fn part2(crab_positions: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let part2_costs = costs(crab_positions, cost_of_alignment_part_2);
+        let part2_min_cost = part2_costs.iter().min().ok_or("no crabs")?;
+        Ok(*part2_min_cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn example_part1() {
+        let input = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(Day07::part1(&input).unwrap(), 37);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(Day07::part2(&input).unwrap(), 168);
+    }
+
+    #[test]
+    fn aligns_on_the_rightmost_crab() {
+        let input = Day07::parse("0,3,3,3").unwrap();
+        assert_eq!(Day07::part1(&input).unwrap(), 3);
+        assert_eq!(optimal_cost_part_1(&input), Some(3));
+    }
+}
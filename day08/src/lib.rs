@@ -0,0 +1,256 @@
+//! day 8, decoding scrambled seven segment displays. a Line holds the ten signal patterns
+//! and the four output digits, decode_line works out which pattern is which digit
+
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use aoc_common::{trace, ParseError, Solution};
+
+/// the signal patterns of one display and its output digits, every pattern is a set of segments a to g
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    pub input: Vec<String>,
+    pub output: Vec<String>,
+}
+
+impl Line {
+    /// a line from its patterns, nothing is checked until it is decoded
+    pub fn new(input: &[&str], output: &[&str]) -> Line {
+        Line { input: input.iter().map(|p| p.to_string()).collect(), output: output.iter().map(|p| p.to_string()).collect() }
+    }
+}
+
+/// parses 'patterns | output' lines, the patterns have to include the ones decoding starts from
+pub fn parse_input(input: &str) -> Result<Vec<Line>, ParseError> {
+    let mut lines_parsed = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let line_no = i + 1;
+        let input_output_split: Vec<&str> = line.split('|').collect();
+        if input_output_split.len() != 2 {
+            return Err(ParseError::new(line_no, 1, line, "expected signal patterns and output separated by a single '|'"));
+        }
+
+        let input_parsed = parse_patterns(line_no, line, input_output_split[0])?;
+        let output_parsed = parse_patterns(line_no, line, input_output_split[1])?;
+
+        // decoding starts from the digits with a unique number of segments, and needs the 5 segment ones too
+        for len in [2, 3, 4, 5, 7] {
+            if !input_parsed.iter().any(|p: &String| p.len() == len) {
+                return Err(ParseError::at(line_no, line, input_output_split[0].trim(), format!("no signal pattern with {} segments", len)));
+            }
+        }
+
+        lines_parsed.push(Line { input: input_parsed, output: output_parsed });
+    }
+
+    Ok(lines_parsed)
+}
+
+fn parse_patterns(line_no: usize, line: &str, patterns: &str) -> Result<Vec<String>, ParseError> {
+    let mut parsed = Vec::new();
+    for p in patterns.split_whitespace() {
+        if let Some(c) = p.chars().find(|c| !('a'..='g').contains(c)) {
+            return Err(ParseError::at(line_no, line, p, format!("unexpected segment '{}'", c)));
+        }
+        parsed.push(p.to_string());
+    }
+    Ok(parsed)
+}
+
+/// the number of output digits that are 1, 4, 7 or 8
+pub fn part_1(lines: &[Line]) -> u32 {
+    let mut sum = 0;
+    for l in lines {
+        for s in &l.output {
+            sum += match s.len() {
+                2 | 4 | 3 | 7 => 1, // no of segments of digits 1, 4, 7, 8
+                _ => 0
+            }
+        }
+    }
+    sum
+}
+
+fn first_with_len(sets: &HashMap<usize, Vec<HashSet<char>>>, len: usize) -> Result<&HashSet<char>, Box<dyn Error>> {
+    sets.get(&len)
+        .and_then(|v| v.first())
+        .ok_or_else(|| format!("no signal pattern with {} segments", len).into())
+}
+
+/// the line's output as a number
+// each line always contains all numbers 0..10, so we don't need to write a generic algorithm
+// instead we just follow simple process, to figure out the signals from easiest to more complicated
+pub fn decode_line(line: &Line) -> Result<i32, Box<dyn Error>> {
+    let in_str_v: &[String] = &line.input;
+    let mut digits_inputs_sets = HashMap::new();
+    for in_word in in_str_v {
+        let s: HashSet<_> = in_word.chars().collect();
+        match digits_inputs_sets.get_mut(&s.len()) {
+            None => { digits_inputs_sets.insert(s.len(), vec![s]); }
+            Some(v) => { v.push(s); }
+        }
+    }
+
+    // we take number one, which has 2 signals
+    let s1 = first_with_len(&digits_inputs_sets, 2)?;
+    trace!("letter 1 set: {:?}", s1);
+
+    let s7 = first_with_len(&digits_inputs_sets, 3)?;
+    trace!("letter 7 set: {:?}", s7);
+
+    // difference of 1 and 7 is signal mapping for a
+    trace!("mapping for a is: {:?}", s7.difference(s1).collect::<HashSet<_>>());
+
+    // vector of sets of digits 2, 3 and 5
+    let vs235 = digits_inputs_sets.get(&5).ok_or("no signal pattern with 5 segments")?;
+    // the digit that fully contains signals from s1 is 3
+    let mut s3 = &HashSet::new();
+    let mut vs25: Vec<&HashSet<char>> = Vec::new();
+    for s in vs235 {
+        let intersection: HashSet<_> = s.intersection(s1).cloned().collect();
+        trace!("intersection is: {:?}", intersection);
+        if intersection.len() == 2 {
+            s3 = s;
+        } else {
+            vs25.push(s);
+        }
+    }
+    trace!("s3 = {:?}", s3);
+    let s4 = first_with_len(&digits_inputs_sets, 4)?;
+    trace!("s4 = {:?}", s4);
+    // {s4} - {s3} gives us mapping for signal b
+    let mb: HashSet<_> = s4.difference(s3).copied().collect();
+    trace!("mb = {:?}", mb);
+
+    // out of vs25 the one that contains {b} is 5
+    let mut s5: HashSet<char> = HashSet::new();
+    let mut s2: HashSet<_> = HashSet::new();
+    for s in &vs25 {
+        if s.intersection(&mb).collect::<HashSet<&char>>().len() == 1 {
+            s5 = s.iter().copied().collect();
+        } else {
+            s2 = s.iter().copied().collect();
+        }
+    }
+
+    trace!("s5 is {:?}", s5);
+
+    // signal c is {1} - {5}
+    let mc: HashSet<_> = s1.difference(&s5).cloned().collect();
+    trace!("mc = {:?}", mc);
+
+    // md is s4 - s1 - mb
+    let md: HashSet<_> = s4.difference(s1).cloned().collect::<HashSet<_>>().difference(&mb).cloned().collect();
+    trace!("md is {:?}", md);
+
+    // me is s2 - s3
+    let me: HashSet<_> = s2.difference(s3).cloned().collect();
+    trace!("me is {:?}", me);
+
+    // mf is s1 - s2
+    trace!("mf is {:?}", s1.difference(&s2).collect::<HashSet<_>>());
+
+    // mg is s2 - s7 - md - me
+    trace!("mg is {:?}", s2.iter().filter(|c| !s7.contains(c) && !md.contains(c) && !me.contains(c)).collect::<HashSet<_>>());
+
+    // so far we have s1, s2, s3, s4, s5, s7
+    // let's get the rest
+    let s8: HashSet<_> = first_with_len(&digits_inputs_sets, 7)?.clone();
+    trace!("s8 is: {:?}", s8);
+
+    let s6: HashSet<_> = s8.difference(&mc).cloned().collect();
+    trace!("s6 is {:?}", s6);
+
+    let s0: HashSet<_> = s8.difference(&md).cloned().collect();
+    trace!("s0 is {:?}", s0);
+
+    let s9: HashSet<_> = s8.difference(&me).cloned().collect();
+    trace!("s9 is {:?}", s9);
+
+    // since we have the hashsets with all numbers, lets just compare them with the output
+
+    let mut out_num = 0;
+    for o in &line.output {
+        let o_set: HashSet<_> = o.chars().collect();
+        let n = match o_set {
+            x if x == s0 => 0,
+            x if x == *s1 => 1,
+            x if x == s2 => 2,
+            x if x == *s3 => 3,
+            x if x == *s4 => 4,
+            x if x == s5 => 5,
+            x if x == s6 => 6,
+            x if x == *s7 => 7,
+            x if x == s8 => 8,
+            x if x == s9 => 9,
+            _ => return Err(format!("failed to match output '{}'", o).into()),
+        };
+
+        out_num *= 10;
+        out_num += n;
+        trace!("the number is {}", n);
+    }
+
+    Ok(out_num)
+}
+
+/// the sum of every line's output
+pub fn part_2(lines: &[Line]) -> Result<i32, Box<dyn Error>> {
+    let mut sum = 0;
+    for l in lines {
+        let n = decode_line(l)?;
+        sum += n;
+    }
+    Ok(sum)
+}
+
+/// the Solution of day 8
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    const TITLE: &'static str = "Seven Segment Search";
+
+    type Input = Vec<Line>;
+    type Answer1 = u32;
+    type Answer2 = i32;
+    type Options = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part_1(lines))
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        part_2(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn example_part1() {
+        let input = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(Day08::part1(&input).unwrap(), 26);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(Day08::part2(&input).unwrap(), 61229);
+    }
+
+    #[test]
+    fn lines_built_directly() {
+        let patterns = ["acedgfb", "cdfbe", "gcdfa", "fbcad", "dab", "cefabd", "cdfgeb", "eafb", "cagedb", "ab"];
+        let line = Line::new(&patterns, &["cdfeb", "fcadb", "cdfeb", "cdbaf"]);
+        assert_eq!(decode_line(&line).unwrap(), 5353);
+        assert_eq!(part_1(&[line]), 0);
+    }
+}
@@ -0,0 +1,223 @@
+//! day 9, smoke basins in a height map. a Map can be parsed or built from a Grid of heights,
+//! the local minimums are the low points and every basin is grown from one of them
+
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use aoc_common::options::{parse_option, unknown_option};
+use aoc_common::{debug, Grid, Options, ParseError, Solution};
+
+/// the height of the walls between the basins in the puzzle
+pub const WALL_HEIGHT: i32 = 9;
+
+/// the height that separates the basins
+pub struct Day09Options {
+    // points of this height don't belong to any basin
+    pub wall_height: i32,
+}
+
+impl Default for Day09Options {
+    fn default() -> Self {
+        Day09Options { wall_height: WALL_HEIGHT }
+    }
+}
+
+impl Options for Day09Options {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "wall_height" => self.wall_height = parse_option(key, value)?,
+            _ => return Err(unknown_option(key, self)),
+        }
+        Ok(())
+    }
+
+    fn values(&self) -> Vec<(&'static str, String)> {
+        vec![("wall_height", self.wall_height.to_string())]
+    }
+}
+
+/// the height of every point
+pub struct Map {
+    heights: Grid<i32>,
+}
+
+impl Map {
+    /// a map of the heights, the parsed ones are single digits but any heights work
+    pub fn new(heights: Grid<i32>) -> Map {
+        Map { heights }
+    }
+
+    /// the point if it is lower than all its neighbours, None if it isn't or is outside of the map
+    pub fn is_local_minimum(&self, x: i32, y: i32) -> Option<Coord> {
+        let c = match self.get_coord(x, y) {
+            Some(c) => c,
+            None => { return None; }
+        };
+
+        for (x, y) in self.heights.neighbours_4(x, y) {
+            if let Some(c2) = self.get_coord(x, y) {
+                if c2.d <= c.d {
+                    return None;
+                }
+            }
+        }
+
+        Some(c)
+    }
+
+    /// the point with its height, None outside of the map
+    pub fn get_coord(&self, x: i32, y: i32) -> Option<Coord> {
+        self.heights.get(x, y).map(|d| Coord { x, y, d: *d })
+    }
+}
+
+impl Map {
+    /// the heights as a grid
+    pub fn heights(&self) -> &Grid<i32> {
+        &self.heights
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.heights)
+    }
+}
+
+/// parses the grid of single digit heights
+pub fn parse_input(input: &str) -> Result<Map, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+
+    Ok(Map::new(Grid::parse_digits(&lines)?))
+}
+
+/// the sum of the low points' risk levels
+pub fn part_1(local_minimums: &[Coord]) -> i32 {
+    // risk level is depth + 1
+    local_minimums.iter().map(|c| c.d + 1).sum()
+}
+
+/// every low point, row by row
+pub fn find_local_minimums(m: &Map) -> Vec<Coord> {
+    let mut mins = Vec::new();
+    for (x, y) in m.heights.coords() {
+        if let Some(c) = m.is_local_minimum(x, y) {
+            mins.push(c)
+        }
+    }
+    mins
+}
+
+/// the points that flow down to the low point, points of wall_height belong to no basin
+pub fn find_basin(map: &Map, local_minimum: &Coord, wall_height: i32) -> Vec<Coord> {
+    let mut processed = HashSet::<Coord>::new();
+    let mut to_process = vec![*local_minimum];
+    let mut basin: HashSet<Coord> = HashSet::new();
+
+    while let Some(c) = to_process.pop() {
+        processed.insert(c);
+        basin.insert(c);
+
+        for (x, y) in map.heights.neighbours_4(c.x, c.y) {
+            if let Some(c2) = map.get_coord(x, y) {
+                if !processed.contains(&c2) && (c2.d > c.d) && (c2.d != wall_height) {
+                    to_process.push(c2);
+                }
+            }
+        }
+    }
+
+    basin.into_iter().collect()
+}
+
+/// a point of the map with its height d, x to the right and y down
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub struct Coord {
+    pub x: i32,
+    pub y: i32,
+    pub d: i32,
+}
+
+impl Coord {
+    pub fn new(x: i32, y: i32, d: i32) -> Coord {
+        Coord { x, y, d }
+    }
+
+    pub fn position(&self) -> (i32, i32) {
+        (self.x, self.y)
+    }
+}
+
+/// multiplies the sizes of the three largest basins
+pub fn part_2(map: &Map, local_minimums: &[Coord], wall_height: i32) -> Result<usize, Box<dyn Error>> {
+    let mut basin_sizes = Vec::new();
+    for min in local_minimums {
+        let b = find_basin(map, min, wall_height);
+        basin_sizes.push(b.len());
+        debug!("found basin with size {} at {:?}", b.len(), min.position());
+    }
+    if basin_sizes.len() < 3 {
+        return Err(format!("expected at least 3 basins, found {}", basin_sizes.len()).into());
+    }
+    basin_sizes.sort_unstable();
+    Ok(basin_sizes[basin_sizes.len() - 1] * basin_sizes[basin_sizes.len() - 2] * basin_sizes[basin_sizes.len() - 3])
+}
+
+/// the Solution of day 9
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    const TITLE: &'static str = "Smoke Basin";
+
+    type Input = Map;
+    type Answer1 = i32;
+    type Answer2 = usize;
+    type Options = Day09Options;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(map: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let local_minimums = find_local_minimums(map);
+        Ok(part_1(&local_minimums))
+    }
+
+    fn part2(map: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        Self::part2_with(map, &Day09Options::default())
+    }
+
+    fn part2_with(map: &Self::Input, options: &Day09Options) -> Result<Self::Answer2, Box<dyn Error>> {
+        let local_minimums = find_local_minimums(map);
+        part_2(map, &local_minimums, options.wall_height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn example_part1() {
+        let input = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(Day09::part1(&input).unwrap(), 15);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(Day09::part2(&input).unwrap(), 1134);
+    }
+
+    #[test]
+    fn maps_built_directly() {
+        let rows = vec![vec![1, 9, 0], vec![2, 9, 1], vec![3, 9, 2]];
+        let map = Map::new(Grid::from_rows(rows).unwrap());
+        assert_eq!(find_local_minimums(&map), vec![Coord::new(0, 0, 1), Coord::new(2, 0, 0)]);
+        assert_eq!(map.get_coord(1, 2), Some(Coord::new(1, 2, 9)));
+        assert_eq!(find_basin(&map, &Coord::new(2, 0, 0), WALL_HEIGHT).len(), 3);
+    }
+}
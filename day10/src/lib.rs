@@ -0,0 +1,301 @@
+//! day 10, syntax scoring of bracket lines. parse_line tells corrupted lines from incomplete
+//! ones, the first score the wrong bracket and the others the brackets that would complete them
+
+use std::error::Error;
+use aoc_common::options::{format_option_list, parse_option, parse_option_list, unknown_option};
+use aoc_common::{debug, trace, Options, ParseError, Solution, Streaming};
+
+/// the scores of the brackets and how completion scores add up
+// scores are given for ) ] } > in that order
+#[derive(Clone)]
+pub struct Day10Options {
+    pub error_scores: [i64; 4],
+    pub completion_scores: [i64; 4],
+    // the completion score is multiplied by this before every closing bracket is added
+    pub completion_multiplier: i64,
+}
+
+impl Default for Day10Options {
+    fn default() -> Self {
+        Day10Options { error_scores: [3, 57, 1197, 25137], completion_scores: [1, 2, 3, 4], completion_multiplier: 5 }
+    }
+}
+
+impl Options for Day10Options {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "error_scores" => self.error_scores = parse_option_list(key, value)?,
+            "completion_scores" => self.completion_scores = parse_option_list(key, value)?,
+            "completion_multiplier" => self.completion_multiplier = parse_option(key, value)?,
+            _ => return Err(unknown_option(key, self)),
+        }
+        Ok(())
+    }
+
+    fn values(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("error_scores", format_option_list(&self.error_scores)),
+            ("completion_scores", format_option_list(&self.completion_scores)),
+            ("completion_multiplier", self.completion_multiplier.to_string()),
+        ]
+    }
+
+    // below 1 the completion scores stop telling the lines apart, negative scores would do the same
+    fn validate(&self) -> Result<(), String> {
+        if self.completion_multiplier < 1 {
+            return Err("completion_multiplier has to be at least 1".to_string());
+        }
+        if self.error_scores.iter().chain(&self.completion_scores).any(|s| *s < 0) {
+            return Err("error_scores and completion_scores can't be negative".to_string());
+        }
+        Ok(())
+    }
+}
+
+const CLOSING_BRACKETS: [char; 4] = [')', ']', '}', '>'];
+
+fn score_of(c: &char, scores: &[i64; 4]) -> i64 {
+    CLOSING_BRACKETS.iter().position(|b| b == c).map_or(0, |i| scores[i])
+}
+
+/// the missing closing brackets of an incomplete line, or the first wrong one of a corrupted line as the error
+pub fn parse_line(line: &str) -> Result<Vec<char>, char> {
+    trace!("parsing line: {}", line);
+    let mut stack: Vec<char> = Vec::new();
+
+    for c in line.chars() {
+        if is_closing_bracket(&c) {
+            if let Some(opening) = stack.pop() {
+                trace!("found closing {} which should match our opening: {}", c, opening);
+                if !opening_matches_closing(&opening, &c) {
+                    debug!("{} doesn't match {} in {}", opening, c, line);
+                    return Err(c);
+                }
+            }
+        } else {
+            stack.push(c);
+        }
+    }
+
+    let closing_brackets = gen_closing_brackets(&stack);
+
+    Ok(closing_brackets)
+}
+
+fn gen_closing_brackets(openings: &[char]) -> Vec<char> {
+    let mut closings = Vec::new();
+    for c in openings.iter().rev() {
+        closings.push(closing_for_opening(c));
+    }
+
+    closings
+}
+
+/// the completion score of the closing brackets, in the order they would be added
+pub fn calc_closing_score(closings: &[char], options: &Day10Options) -> i64 {
+    let mut sum = 0;
+    for c in closings {
+        sum *= options.completion_multiplier;
+        sum += score_of(c, &options.completion_scores);
+    }
+    sum
+}
+
+fn closing_for_opening(opening: &char) -> char {
+    match opening {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        '<' => '>',
+        _ => '?'
+    }
+}
+
+fn opening_matches_closing(o: &char, c: &char) -> bool {
+    match o {
+        '(' => c == &')',
+        '[' => c == &']',
+        '{' => c == &'}',
+        '<' => c == &'>',
+        _ => false,
+    }
+}
+
+fn is_closing_bracket(c: &char) -> bool {
+    CLOSING_BRACKETS.contains(c)
+}
+
+/// the middle score once sorted, panics on no scores
+pub fn find_average_score(scores: &[i64]) -> i64 {
+    let mut s: Vec<i64> = scores.to_vec();
+    s.sort_unstable();
+
+    let avg_index = s.len() / 2;
+
+    s[avg_index]
+}
+
+/// the sum of the wrong brackets' error scores
+pub fn calc_err_score(err_chars: &[char], options: &Day10Options) -> i64 {
+    err_chars.iter().map(|c| score_of(c, &options.error_scores)).sum()
+}
+
+/// reads the lines, only brackets are allowed
+pub fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    let mut lines = Vec::new();
+    for (i, l) in input.lines().enumerate() {
+        check_brackets(i + 1, l)?;
+        lines.push(l.to_string());
+    }
+    Ok(lines)
+}
+
+fn check_brackets(line_no: usize, l: &str) -> Result<(), ParseError> {
+    match l.chars().enumerate().find(|(_, c)| !"([{<)]}>".contains(*c)) {
+        Some((col, c)) => Err(ParseError::new(line_no, col + 1, c, "expected a bracket")),
+        None => Ok(()),
+    }
+}
+
+/// the wrong chars of corrupted lines and the closing scores of incomplete lines
+pub fn score_lines(lines: &[String], options: &Day10Options) -> (Vec<char>, Vec<i64>) {
+    let mut err_chars = Vec::new();
+    let mut closing_scores = Vec::new();
+    for line in lines {
+        match parse_line(line) {
+            Ok(closing) => {
+                let closing_score = calc_closing_score(&closing, options);
+                closing_scores.push(closing_score);
+            }
+            Err(c) => {
+                err_chars.push(c);
+            }
+        }
+    }
+
+    (err_chars, closing_scores)
+}
+
+/// scores every line as it comes in, the state Day10 streams part 1 with. part 2's median
+/// needs the closing score of every incomplete line, so only their number is kept here
+pub struct LineScorer {
+    options: Day10Options,
+    pub err_score: i64,
+    pub incomplete_lines: u64,
+}
+
+impl LineScorer {
+    /// nothing scored yet
+    pub fn new(options: &Day10Options) -> LineScorer {
+        LineScorer { options: options.clone(), err_score: 0, incomplete_lines: 0 }
+    }
+
+    /// scores one line, it isn't checked for other characters than brackets
+    pub fn add(&mut self, line: &str) {
+        match parse_line(line) {
+            Ok(_) => self.incomplete_lines += 1,
+            Err(c) => self.err_score += score_of(&c, &self.options.error_scores),
+        }
+    }
+}
+
+/// the Solution of day 10, part 1 sums the error scores and part 2 takes the middle completion score.
+/// only part 1 can be streamed
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    const TITLE: &'static str = "Syntax Scoring";
+
+    type Input = Vec<String>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+    type Options = Day10Options;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        Self::part1_with(lines, &Day10Options::default())
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        Self::part2_with(lines, &Day10Options::default())
+    }
+
+    fn part1_with(lines: &Self::Input, options: &Day10Options) -> Result<Self::Answer1, Box<dyn Error>> {
+        let (err_chars, _) = score_lines(lines, options);
+        Ok(calc_err_score(&err_chars, options))
+    }
+
+    fn part2_with(lines: &Self::Input, options: &Day10Options) -> Result<Self::Answer2, Box<dyn Error>> {
+        let (_, closing_scores) = score_lines(lines, options);
+        if closing_scores.is_empty() {
+            return Err("no incomplete lines".into());
+        }
+        Ok(find_average_score(&closing_scores))
+    }
+}
+
+impl Streaming for Day10 {
+    type State = LineScorer;
+    const STREAMED_PARTS: &'static [u32] = &[1];
+
+    fn start(options: &Day10Options) -> LineScorer {
+        LineScorer::new(options)
+    }
+
+    fn feed(scorer: &mut LineScorer, line_no: usize, line: &str) -> Result<(), ParseError> {
+        check_brackets(line_no, line)?;
+        scorer.add(line);
+        Ok(())
+    }
+
+    fn finish1(scorer: &LineScorer) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(scorer.err_score)
+    }
+
+    // the median needs every closing score, keeping them would grow with the input
+    fn finish2(_scorer: &LineScorer) -> Result<Self::Answer2, Box<dyn Error>> {
+        Err("part 2 needs the closing score of every incomplete line and can't be streamed".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn example_part1() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part1(&input).unwrap(), 26397);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part2(&input).unwrap(), 288957);
+    }
+
+    #[test]
+    fn other_scoring() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        let mut options = Day10Options::default();
+        options.set("error_scores", "1,1,1,1").unwrap();
+        // five of the example lines are corrupted
+        assert_eq!(Day10::part1_with(&input, &options).unwrap(), 5);
+        assert!(options.set("completion_scores", "1,2").is_err());
+        options.set("completion_multiplier", "0").unwrap();
+        assert!(options.validate().is_err());
+    }
+
+    #[test]
+    fn streams_the_example() {
+        let scorer = aoc_common::stream::<Day10, _>(EXAMPLE.as_bytes(), &Day10Options::default()).unwrap();
+        assert_eq!((Day10::finish1(&scorer).unwrap(), scorer.incomplete_lines), (26397, 5));
+        assert!(Day10::finish2(&scorer).is_err());
+    }
+}
@@ -0,0 +1,234 @@
+//! day 11, flashing dumbo octopuses. a Map is built from a grid of energies and
+//! moved forward with step, the counters are public
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use aoc_common::options::{parse_option, unknown_option};
+use aoc_common::{debug, Grid, Options, ParseError, Solution};
+
+/// the steps part 1 counts, when part 2 gives up and the energy an octopus flashes at
+pub struct Day11Options {
+    pub steps_part1: u32,
+    // part 2 gives up when the octopuses haven't flashed together after this many steps
+    pub step_limit: u32,
+    // an octopus flashes when its energy goes above this
+    pub flash_threshold: i32,
+}
+
+impl Default for Day11Options {
+    fn default() -> Self {
+        Day11Options { steps_part1: 100, step_limit: 10000, flash_threshold: 9 }
+    }
+}
+
+impl Options for Day11Options {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "steps_part1" => self.steps_part1 = parse_option(key, value)?,
+            "step_limit" => self.step_limit = parse_option(key, value)?,
+            "flash_threshold" => self.flash_threshold = parse_option(key, value)?,
+            _ => return Err(unknown_option(key, self)),
+        }
+        Ok(())
+    }
+
+    fn values(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("steps_part1", self.steps_part1.to_string()),
+            ("step_limit", self.step_limit.to_string()),
+            ("flash_threshold", self.flash_threshold.to_string()),
+        ]
+    }
+}
+
+/// the octopuses, the flashes counted so far and the number of steps taken
+pub struct Map {
+    octopuses: Grid<Octopus>,
+    flash_threshold: i32,
+    pub total_flashes_number: u64,
+    current_step_flashes_number: u64,
+    pub step: u32,
+}
+
+struct Octopus {
+    energy: i32,
+    flashed: bool,
+}
+
+impl Map {
+    /// octopuses flash when their energy goes above 9
+    pub fn new(energies: &Grid<i32>) -> Map {
+        Map::with_threshold(energies, Day11Options::default().flash_threshold)
+    }
+
+    /// octopuses flash when their energy goes above flash_threshold
+    pub fn with_threshold(energies: &Grid<i32>, flash_threshold: i32) -> Map {
+        let octopuses = energies.map(|e| Octopus { energy: *e, flashed: false });
+
+        Map { octopuses, flash_threshold, total_flashes_number:0, current_step_flashes_number:0, step:0 }
+    }
+
+    fn increment_all(&mut self) {
+        for o in self.octopuses.iter_mut() {
+            o.energy += 1;
+        }
+    }
+
+    fn get_octopus_mut(&mut self, x: i32, y: i32) -> Option<&mut Octopus> {
+        self.octopuses.get_mut(x, y)
+    }
+
+    // flashes the octupus if it's energy > threshold and increments and flashes adjacent octopuses
+    // the field is quite small, so we use recursion to flash the neighbours
+    fn attempt_flash(&mut self, x: i32, y: i32) {
+        let threshold = self.flash_threshold;
+        if let Some(o) = self.get_octopus_mut(x, y) {
+            if o.energy > threshold && !o.flashed {
+                // FLASH
+                o.flashed = true;
+                self.total_flashes_number += 1;
+                self.current_step_flashes_number += 1;
+                let neighbours: Vec<(i32, i32)> = self.octopuses.neighbours_8(x, y).collect();
+                for (x, y) in neighbours {
+                    if let Some(o) = self.get_octopus_mut(x, y) {
+                        o.energy += 1;
+                        self.attempt_flash(x, y);
+                    }
+                }
+            }
+        }
+    }
+
+    fn flash_all(&mut self) {
+        let coords: Vec<(i32, i32)> = self.octopuses.coords().collect();
+        for (x, y) in coords {
+            self.attempt_flash(x, y);
+        }
+    }
+
+    fn zero_out_flashed_octopuses(&mut self) {
+        for o in self.octopuses.iter_mut() {
+            if o.flashed {
+                o.energy = 0;
+                o.flashed = false;
+            }
+        }
+        self.current_step_flashes_number = 0;
+    }
+
+    /// energy of every octopus, the ones that flashed during the last step are at 0
+    pub fn energies(&self) -> Grid<i32> {
+        self.octopuses.map(|o| if o.flashed { 0 } else { o.energy })
+    }
+
+    /// returns true if all octopuses flashed
+    pub fn step(&mut self) -> bool {
+        // first we increment all octopuses
+        self.increment_all();
+        self.flash_all();
+        self.step += 1;
+        let all_flashed = self.current_step_flashes_number == (self.octopuses.width() * self.octopuses.height()) as u64;
+        debug!("step {}: {} flashes, {} in total", self.step, self.current_step_flashes_number, self.total_flashes_number);
+        // zeroing out also after a synchronised step, so the map can keep stepping
+        self.zero_out_flashed_octopuses();
+        all_flashed
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.octopuses.rows() {
+            for n in row {
+                write!(f, "{}", n.energy)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// parses the grid of single digit energies
+pub fn parse_input(input: &str) -> Result<Grid<i32>, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    Grid::parse_digits(&lines)
+}
+
+/// the Solution of day 11
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    const TITLE: &'static str = "Dumbo Octopus";
+
+    type Input = Grid<i32>;
+    type Answer1 = u64;
+    type Answer2 = u32;
+    type Options = Day11Options;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(energies: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        Self::part1_with(energies, &Day11Options::default())
+    }
+
+    fn part2(energies: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        Self::part2_with(energies, &Day11Options::default())
+    }
+
+    fn part1_with(energies: &Self::Input, options: &Day11Options) -> Result<Self::Answer1, Box<dyn Error>> {
+        let mut map = Map::with_threshold(energies, options.flash_threshold);
+        for _ in 0..options.steps_part1 {
+            map.step();
+        }
+        Ok(map.total_flashes_number)
+    }
+
+    fn part2_with(energies: &Self::Input, options: &Day11Options) -> Result<Self::Answer2, Box<dyn Error>> {
+        let mut map = Map::with_threshold(energies, options.flash_threshold);
+        for _ in 0..options.step_limit {
+            if map.step() {
+                return Ok(map.step);
+            }
+        }
+        Err(format!("couldn't find, when all octopuses flash together within first {} steps", options.step_limit).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn example_part1() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part1(&input).unwrap(), 1656);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part2(&input).unwrap(), 195);
+    }
+
+    #[test]
+    fn fewer_steps() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        let options = Day11Options { steps_part1: 10, step_limit: 194, ..Day11Options::default() };
+        assert_eq!(Day11::part1_with(&input, &options).unwrap(), 204);
+        assert!(Day11::part2_with(&input, &options).is_err());
+    }
+
+    #[test]
+    fn keeps_stepping_after_synchronising() {
+        let mut map = Map::new(&Day11::parse(EXAMPLE).unwrap());
+        while !map.step() {}
+        assert_eq!(map.step, 195);
+        assert!(!map.step());
+        assert!(map.energies().iter().all(|e| *e == 1));
+    }
+}
@@ -0,0 +1,247 @@
+//! day 12, paths through a cave system. Connections can be parsed or built with
+//! connect, find_paths_part_1/2 return every path from start to end
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use aoc_common::{trace, ParseError, Solution};
+
+/// the caves and the passages between them. caves with upper case names are big
+#[derive(Debug, Default)]
+pub struct Connections {
+    conn: HashMap<String, Vec<String>>,
+}
+
+impl Connections {
+    /// a cave system without any caves
+    pub fn new() -> Connections {
+        Connections{conn: HashMap::new()}
+    }
+
+    /// adds a passage, passages go both ways
+    pub fn connect(&mut self, a: &str, b: &str) {
+        self.add(a, b);
+        self.add(b, a);
+    }
+
+    /// the caves a passage leads to from the cave, in the order they were connected
+    pub fn neighbours(&self, cave: &str) -> &[String] {
+        self.conn.get(cave).map_or(&[], |v| v.as_slice())
+    }
+
+    fn add(&mut self, from: &str, to: &str) {
+        match self.conn.get_mut(from) {
+            None => {
+                self.conn.insert(from.to_string(), vec![to.to_string()]);
+            }
+            Some(v) => {
+                v.push(to.to_string());
+            }
+        }
+    }
+    /// every cave name once, sorted
+    pub fn caves(&self) -> Vec<&str> {
+        let mut caves: Vec<&str> = self.conn.keys().map(|c| c.as_str()).collect();
+        caves.sort_unstable();
+        caves
+    }
+
+    /// every connection once with the smaller name first, sorted
+    pub fn edges(&self) -> Vec<(&str, &str)> {
+        let mut edges: Vec<(&str, &str)> = self.conn.iter()
+            .flat_map(|(from, tos)| tos.iter().map(move |to| (from.as_str(), to.as_str())))
+            .filter(|(from, to)| from <= to)
+            .collect();
+        edges.sort_unstable();
+        edges.dedup();
+        edges
+    }
+}
+
+/// parses 'a-b' lines, one passage each
+pub fn parse_input_lines_into_caves<S: AsRef<str>>(input_lines: &[S]) -> Result<Connections, ParseError> {
+    let mut connections = Connections::new();
+    for (i, l) in input_lines.iter().enumerate() {
+        let l = l.as_ref();
+        let split: Vec<&str> = l.trim().split('-').collect();
+        if split.len() != 2 {
+            return Err(ParseError::new(i + 1, 1, l, "expected exactly one '-' between two caves"));
+        }
+        let c1_str = split[0];
+        let c2_str = split[1];
+        connections.connect(c1_str, c2_str);
+    }
+    Ok(connections)
+}
+
+/// the caves of one path, in the order they are visited
+#[derive(Debug, Clone)]
+pub struct Path {
+    caves: Vec<String>,
+    double_visited: bool, // marks a double visit to small cave (part2)
+}
+
+impl Path {
+    fn new() -> Path {
+        Path { caves: vec![], double_visited: false}
+    }
+    fn add_cave(&mut self, cave: String) {
+        self.caves.push(cave);
+    }
+
+    pub fn caves(&self) -> &[String] {
+        &self.caves
+    }
+
+    fn contains(&self, cave: &str) -> bool {
+        for c in &self.caves {
+            if cave == c {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+// start,A,b,end
+impl Display for Path {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.caves.join(","))
+    }
+}
+
+/// every path from start to end that visits the small caves at most once
+pub fn find_paths_part_1(conns: &Connections) -> Vec<Path> {
+    let mut paths = vec![];
+    let mut initial_path = Path::new();
+    initial_path.add_cave("start".to_owned());
+    let mut to_search = vec![initial_path];
+
+    while let Some(path) = to_search.pop() {
+        let curr_cave = path.caves.last().unwrap();
+        if curr_cave == "end" {
+            paths.push(path.clone());
+            continue;
+        }
+
+        if let Some(curr_cave_conns) = conns.conn.get(curr_cave) {
+            for connected_cave in curr_cave_conns {
+                if (&connected_cave.to_uppercase() == connected_cave) || !path.contains(connected_cave) {
+                    let mut new_path = path.clone();
+                    new_path.add_cave(connected_cave.to_owned());
+                    to_search.push(new_path);
+                }
+            }
+        }
+    }
+
+    paths
+}
+
+/// like part 1, one small cave other than start and end may be visited twice
+pub fn find_paths_part_2(conns: &Connections) -> Vec<Path> {
+    let mut paths = vec![];
+    let mut initial_path = Path::new();
+    initial_path.add_cave("start".to_owned());
+    let mut to_search = vec![initial_path];
+
+    while let Some(path) = to_search.pop() {
+        let curr_cave = path.caves.last().unwrap();
+        if curr_cave == "end" {
+            paths.push(path.clone());
+            continue;
+        }
+
+        if let Some(curr_cave_conns) = conns.conn.get(curr_cave) {
+            for connected_cave in curr_cave_conns {
+                if connected_cave == "start" {
+                    continue;
+                }
+                if (&connected_cave.to_uppercase() == connected_cave) || !path.contains(connected_cave) {
+                    let mut new_path = path.clone();
+                    new_path.add_cave(connected_cave.to_owned());
+                    to_search.push(new_path);
+                } else if !path.double_visited {
+                    let mut new_path = path.clone();
+                    new_path.add_cave(connected_cave.to_owned());
+                    new_path.double_visited = true;
+                    to_search.push(new_path);
+                }
+            }
+        }
+    }
+
+    paths
+}
+
+/// the Solution of day 12, both parts count the paths
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    const TITLE: &'static str = "Passage Pathing";
+
+    type Input = Connections;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Options = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let input_lines: Vec<&str> = input.lines().collect();
+        parse_input_lines_into_caves(&input_lines)
+    }
+
+    fn part1(conns: &Self::Input) -> Result<Self::Answer1, Box<dyn Error>> {
+        let paths_part1 = find_paths_part_1(conns);
+        trace!("part1: paths: {:?}", paths_part1);
+        Ok(paths_part1.len())
+    }
+
+    fn part2(conns: &Self::Input) -> Result<Self::Answer2, Box<dyn Error>> {
+        let paths_part2 = find_paths_part_2(conns);
+        trace!("part2: paths: {:?}", paths_part2);
+        Ok(paths_part2.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn example_part1() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part1(&input).unwrap(), 10);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part2(&input).unwrap(), 36);
+    }
+
+    #[test]
+    fn larger_examples() {
+        let input = Day12::parse(include_str!("../example_2")).unwrap();
+        assert_eq!(Day12::part1(&input).unwrap(), 19);
+        assert_eq!(Day12::part2(&input).unwrap(), 103);
+
+        let input = Day12::parse(include_str!("../example_3")).unwrap();
+        assert_eq!(Day12::part1(&input).unwrap(), 226);
+        assert_eq!(Day12::part2(&input).unwrap(), 3509);
+    }
+
+    #[test]
+    fn caves_built_directly() {
+        let mut conns = Connections::new();
+        conns.connect("start", "A");
+        conns.connect("A", "end");
+        conns.connect("A", "b");
+        let mut paths: Vec<String> = find_paths_part_1(&conns).iter().map(|p| p.to_string()).collect();
+        paths.sort();
+        assert_eq!(paths, vec!["start,A,b,A,end", "start,A,end"]);
+        assert_eq!(conns.neighbours("b"), ["A"]);
+    }
+}